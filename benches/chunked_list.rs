@@ -0,0 +1,60 @@
+use std::collections::VecDeque;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use doubly_linked_list::chunked_list::ChunkedList;
+use doubly_linked_list::linked_list::LinkedList;
+
+fn iterate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("iterate");
+
+    for size in [1_000, 100_000] {
+        let linked_list = (0 .. size).collect::<LinkedList<u32>>();
+        let chunked_list = (0 .. size).collect::<ChunkedList<u32>>();
+        let vec_deque = (0 .. size).collect::<VecDeque<u32>>();
+
+        group.bench_with_input(BenchmarkId::new("LinkedList", size), &linked_list, |b, list| {
+            b.iter(|| black_box(list.iter().sum::<u32>()))
+        });
+        group.bench_with_input(BenchmarkId::new("ChunkedList", size), &chunked_list, |b, list| {
+            b.iter(|| black_box(list.iter().sum::<u32>()))
+        });
+        group.bench_with_input(BenchmarkId::new("VecDeque", size), &vec_deque, |b, list| {
+            b.iter(|| black_box(list.iter().sum::<u32>()))
+        });
+    }
+
+    group.finish();
+}
+
+fn insert_middle(c: &mut Criterion) {
+    let mut group = c.benchmark_group("insert_middle");
+
+    for size in [100, 1_000] {
+        group.bench_with_input(BenchmarkId::new("LinkedList", size), &size, |b, &size| {
+            b.iter(|| {
+                let mut list = LinkedList::new();
+                (0 .. size).for_each(|i| list.insert(i / 2, i));
+                list
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("ChunkedList", size), &size, |b, &size| {
+            b.iter(|| {
+                let mut list = ChunkedList::new();
+                (0 .. size).for_each(|i| list.insert(i / 2, i));
+                list
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("VecDeque", size), &size, |b, &size| {
+            b.iter(|| {
+                let mut list = VecDeque::new();
+                (0 .. size).for_each(|i| list.insert(i / 2, i));
+                list
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, iterate, insert_middle);
+criterion_main!(benches);
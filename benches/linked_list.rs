@@ -0,0 +1,288 @@
+use std::collections::{self, VecDeque};
+
+use criterion::measurement::WallTime;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkGroup, BenchmarkId, Criterion};
+use doubly_linked_list::linked_list::LinkedList;
+
+type StdLinkedList<T> = collections::LinkedList<T>;
+
+const SIZES: [u64; 2] = [1_000, 100_000];
+
+/// Runs `$bench` for a small and a heap allocated element type
+macro_rules! for_each_element_type {
+    ($group:expr, $bench:ident) => {
+        $bench(&mut $group, "u64", |i| i);
+        $bench(&mut $group, "String", |i| i.to_string());
+    };
+}
+
+fn push_back(c: &mut Criterion) {
+    let mut group = c.benchmark_group("push_back");
+
+    for_each_element_type!(group, push_back_with);
+
+    group.finish();
+}
+
+fn push_back_with<T: 'static>(group: &mut BenchmarkGroup<'_, WallTime>, name: &str, make: fn(u64) -> T) {
+    for size in SIZES {
+        group.bench_with_input(BenchmarkId::new(format!("LinkedList/{name}"), size), &size, |b, &size| {
+            b.iter(|| {
+                let mut list = LinkedList::new();
+                (0 .. size).for_each(|i| list.push_back(make(i)));
+                list
+            })
+        });
+        group.bench_with_input(BenchmarkId::new(format!("std LinkedList/{name}"), size), &size, |b, &size| {
+            b.iter(|| {
+                let mut list = StdLinkedList::new();
+                (0 .. size).for_each(|i| list.push_back(make(i)));
+                list
+            })
+        });
+        group.bench_with_input(BenchmarkId::new(format!("Vec/{name}"), size), &size, |b, &size| {
+            b.iter(|| {
+                let mut list = Vec::new();
+                (0 .. size).for_each(|i| list.push(make(i)));
+                list
+            })
+        });
+        group.bench_with_input(BenchmarkId::new(format!("VecDeque/{name}"), size), &size, |b, &size| {
+            b.iter(|| {
+                let mut list = VecDeque::new();
+                (0 .. size).for_each(|i| list.push_back(make(i)));
+                list
+            })
+        });
+    }
+}
+
+fn pop_front(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pop_front");
+
+    for_each_element_type!(group, pop_front_with);
+
+    group.finish();
+}
+
+fn pop_front_with<T: 'static>(group: &mut BenchmarkGroup<'_, WallTime>, name: &str, make: fn(u64) -> T) {
+    for size in SIZES {
+        group.bench_with_input(BenchmarkId::new(format!("LinkedList/{name}"), size), &size, |b, &size| {
+            b.iter_batched(
+                || (0 .. size).map(make).collect::<LinkedList<_>>(),
+                |mut list| while let Some(data) = list.pop_front() { black_box(data); },
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_with_input(BenchmarkId::new(format!("std LinkedList/{name}"), size), &size, |b, &size| {
+            b.iter_batched(
+                || (0 .. size).map(make).collect::<StdLinkedList<_>>(),
+                |mut list| while let Some(data) = list.pop_front() { black_box(data); },
+                BatchSize::LargeInput,
+            )
+        });
+        // Vec has no cheap pop from the front, so it drains from the front instead
+        group.bench_with_input(BenchmarkId::new(format!("Vec/{name}"), size), &size, |b, &size| {
+            b.iter_batched(
+                || (0 .. size).map(make).collect::<Vec<_>>(),
+                |mut list| list.drain(..).for_each(|data| { black_box(data); }),
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_with_input(BenchmarkId::new(format!("VecDeque/{name}"), size), &size, |b, &size| {
+            b.iter_batched(
+                || (0 .. size).map(make).collect::<VecDeque<_>>(),
+                |mut list| while let Some(data) = list.pop_front() { black_box(data); },
+                BatchSize::LargeInput,
+            )
+        });
+    }
+}
+
+fn insert_middle(c: &mut Criterion) {
+    let mut group = c.benchmark_group("insert_middle");
+
+    // Inserting is O(n) for all of them, so keep the sizes down
+    for size in [100, 1_000] {
+        group.bench_with_input(BenchmarkId::new("LinkedList", size), &size, |b, &size| {
+            b.iter(|| {
+                let mut list = LinkedList::new();
+                (0 .. size).for_each(|i| list.try_insert(i / 2, i).unwrap());
+                list
+            })
+        });
+        // std's LinkedList can only insert by splitting and appending
+        group.bench_with_input(BenchmarkId::new("std LinkedList", size), &size, |b, &size| {
+            b.iter(|| {
+                let mut list = StdLinkedList::new();
+                (0 .. size).for_each(|i| {
+                    let mut back = list.split_off(i / 2);
+                    list.push_back(i);
+                    list.append(&mut back);
+                });
+                list
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("Vec", size), &size, |b, &size| {
+            b.iter(|| {
+                let mut list = Vec::new();
+                (0 .. size).for_each(|i| list.insert(i / 2, i));
+                list
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("VecDeque", size), &size, |b, &size| {
+            b.iter(|| {
+                let mut list = VecDeque::new();
+                (0 .. size).for_each(|i| list.insert(i / 2, i));
+                list
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn get(c: &mut Criterion) {
+    let mut group = c.benchmark_group("get");
+
+    for size in [100, 1_000, 10_000] {
+        let linked_list = (0 .. size).collect::<LinkedList<u64>>();
+        let std_linked_list = (0 .. size).collect::<StdLinkedList<u64>>();
+        let vec = (0 .. size).collect::<Vec<u64>>();
+        let vec_deque = (0 .. size).collect::<VecDeque<u64>>();
+
+        // Spread the lookups over the list, with as many near the middle as near the ends
+        let indices = (0 .. 100).map(|i| (i * 7_919 % size) as usize).collect::<Vec<_>>();
+
+        group.bench_with_input(BenchmarkId::new("LinkedList", size), &indices, |b, indices| {
+            b.iter(|| indices.iter().map(|&i| linked_list.get(i).unwrap()).sum::<u64>())
+        });
+        group.bench_with_input(BenchmarkId::new("std LinkedList", size), &indices, |b, indices| {
+            b.iter(|| indices.iter().map(|&i| std_linked_list.iter().nth(i).unwrap()).sum::<u64>())
+        });
+        group.bench_with_input(BenchmarkId::new("Vec", size), &indices, |b, indices| {
+            b.iter(|| indices.iter().map(|&i| vec[i]).sum::<u64>())
+        });
+        group.bench_with_input(BenchmarkId::new("VecDeque", size), &indices, |b, indices| {
+            b.iter(|| indices.iter().map(|&i| vec_deque[i]).sum::<u64>())
+        });
+    }
+
+    group.finish();
+}
+
+fn iterate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("iterate");
+
+    for_each_element_type!(group, iterate_with);
+
+    group.finish();
+}
+
+fn iterate_with<T: PartialEq>(group: &mut BenchmarkGroup<'_, WallTime>, name: &str, make: fn(u64) -> T) {
+    for size in SIZES {
+        let linked_list = (0 .. size).map(make).collect::<LinkedList<_>>();
+        let std_linked_list = (0 .. size).map(make).collect::<StdLinkedList<_>>();
+        let vec = (0 .. size).map(make).collect::<Vec<_>>();
+        let vec_deque = (0 .. size).map(make).collect::<VecDeque<_>>();
+        let target = make(size / 2);
+
+        group.bench_with_input(BenchmarkId::new(format!("LinkedList/{name}"), size), &target, |b, target| {
+            b.iter(|| linked_list.iter().filter(|x| *x == target).count())
+        });
+        group.bench_with_input(BenchmarkId::new(format!("std LinkedList/{name}"), size), &target, |b, target| {
+            b.iter(|| std_linked_list.iter().filter(|x| *x == target).count())
+        });
+        group.bench_with_input(BenchmarkId::new(format!("Vec/{name}"), size), &target, |b, target| {
+            b.iter(|| vec.iter().filter(|x| *x == target).count())
+        });
+        group.bench_with_input(BenchmarkId::new(format!("VecDeque/{name}"), size), &target, |b, target| {
+            b.iter(|| vec_deque.iter().filter(|x| *x == target).count())
+        });
+    }
+}
+
+fn drop_list(c: &mut Criterion) {
+    let mut group = c.benchmark_group("drop");
+    group.sample_size(10);
+
+    for_each_element_type!(group, drop_with);
+
+    group.finish();
+}
+
+fn drop_with<T: 'static>(group: &mut BenchmarkGroup<'_, WallTime>, name: &str, make: fn(u64) -> T) {
+    for size in [1_000, 1_000_000] {
+        group.bench_with_input(BenchmarkId::new(format!("LinkedList/{name}"), size), &size, |b, &size| {
+            b.iter_batched(|| (0 .. size).map(make).collect::<LinkedList<_>>(), std::mem::drop, BatchSize::LargeInput)
+        });
+        group.bench_with_input(BenchmarkId::new(format!("std LinkedList/{name}"), size), &size, |b, &size| {
+            b.iter_batched(|| (0 .. size).map(make).collect::<StdLinkedList<_>>(), std::mem::drop, BatchSize::LargeInput)
+        });
+        group.bench_with_input(BenchmarkId::new(format!("Vec/{name}"), size), &size, |b, &size| {
+            b.iter_batched(|| (0 .. size).map(make).collect::<Vec<_>>(), std::mem::drop, BatchSize::LargeInput)
+        });
+        group.bench_with_input(BenchmarkId::new(format!("VecDeque/{name}"), size), &size, |b, &size| {
+            b.iter_batched(|| (0 .. size).map(make).collect::<VecDeque<_>>(), std::mem::drop, BatchSize::LargeInput)
+        });
+    }
+}
+
+/// One pass removing the even elements and doubling the odd ones, as an editor would
+fn cursor_edit(c: &mut Criterion) {
+    let mut group = c.benchmark_group("cursor_edit");
+
+    for size in SIZES {
+        group.bench_with_input(BenchmarkId::new("LinkedList", size), &size, |b, &size| {
+            b.iter_batched(
+                || (0 .. size).collect::<LinkedList<u64>>(),
+                |mut list| {
+                    let mut cursor = list.cursor_mut();
+                    while let Some(&data) = cursor.next_data() {
+                        if data % 2 == 0 {
+                            cursor.remove_next();
+                        }
+                        else {
+                            cursor.move_next();
+                            cursor.push(data);
+                        }
+                    }
+
+                    list
+                },
+                BatchSize::LargeInput,
+            )
+        });
+        // Without cursors std's LinkedList has to move every element over to a new list
+        group.bench_with_input(BenchmarkId::new("std LinkedList", size), &size, |b, &size| {
+            b.iter_batched(
+                || (0 .. size).collect::<StdLinkedList<u64>>(),
+                |mut list| {
+                    let mut edited = StdLinkedList::new();
+                    while let Some(data) = list.pop_front() {
+                        if data % 2 != 0 {
+                            edited.push_back(data);
+                            edited.push_back(data);
+                        }
+                    }
+
+                    edited
+                },
+                BatchSize::LargeInput,
+            )
+        });
+        // Editing a Vec in place is quadratic, so it is rebuilt instead
+        group.bench_with_input(BenchmarkId::new("Vec", size), &size, |b, &size| {
+            b.iter_batched(
+                || (0 .. size).collect::<Vec<u64>>(),
+                |list| list.into_iter().filter(|data| data % 2 != 0).flat_map(|data| [data, data]).collect::<Vec<_>>(),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, push_back, pop_front, insert_middle, get, iterate, drop_list, cursor_edit);
+criterion_main!(benches);
@@ -0,0 +1,301 @@
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::time::Duration;
+
+use crate::linked_list::LinkedList;
+
+struct State<T> {
+    list: LinkedList<T>,
+    closed: bool,
+}
+
+/// A `LinkedList` behind a lock, for handing elements between threads.
+///
+/// Consumers can block until an element arrives, and producers block while a
+/// bounded deque is full. After `close` nothing more can be pushed, and the
+/// consumers get whatever is left before they see the deque as finished.
+pub struct BlockingDeque<T> {
+    state: Mutex<State<T>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    capacity: Option<usize>,
+}
+
+impl<T> BlockingDeque<T> {
+    /// Makes a deque without a bound on its length
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(State { list: LinkedList::new(), closed: false }),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+            capacity: None,
+        }
+    }
+
+    /// Makes a deque holding at most `capacity` elements, pushing to it blocks while it is full.
+    ///
+    /// # Panics
+    /// If `capacity` is 0.
+    pub fn with_capacity(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be greater than 0");
+
+        Self {
+            capacity: Some(capacity),
+            ..Self::new()
+        }
+    }
+
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().list.is_empty()
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.lock().closed
+    }
+
+    /// Pushes to the back, waiting for room if the deque is full.
+    ///
+    /// Gives `data` back if the deque is closed.
+    pub fn push_back(&self, data: T) -> Result<(), T> {
+        let Some(mut state) = self.wait_for_room() else {
+            return Err(data);
+        };
+
+        state.list.push_back(data);
+        self.not_empty.notify_one();
+
+        Ok(())
+    }
+
+    /// Pushes to the front, waiting for room if the deque is full.
+    ///
+    /// Gives `data` back if the deque is closed.
+    pub fn push_front(&self, data: T) -> Result<(), T> {
+        let Some(mut state) = self.wait_for_room() else {
+            return Err(data);
+        };
+
+        state.list.push_front(data);
+        self.not_empty.notify_one();
+
+        Ok(())
+    }
+
+    /// Pushes to the back without waiting, giving `data` back if the deque is full or closed
+    pub fn try_push_back(&self, data: T) -> Result<(), T> {
+        let mut state = self.lock();
+        if state.closed || self.is_full(&state) {
+            return Err(data);
+        }
+
+        state.list.push_back(data);
+        self.not_empty.notify_one();
+
+        Ok(())
+    }
+
+    /// Pops the front, waiting for an element if the deque is empty.
+    ///
+    /// Returns None once the deque is both closed and empty.
+    pub fn pop_front_blocking(&self) -> Option<T> {
+        let state = self.lock();
+        let state = self.not_empty
+            .wait_while(state, |state| state.list.is_empty() && !state.closed)
+            .unwrap_or_else(|error| error.into_inner());
+
+        self.pop_front_locked(state)
+    }
+
+    /// Pops the front, waiting at most `timeout` for an element if the deque is empty.
+    ///
+    /// Returns None if the time ran out, or if the deque is both closed and empty.
+    pub fn pop_front_timeout(&self, timeout: Duration) -> Option<T> {
+        let state = self.lock();
+        let (state, _) = self.not_empty
+            .wait_timeout_while(state, timeout, |state| state.list.is_empty() && !state.closed)
+            .unwrap_or_else(|error| error.into_inner());
+
+        self.pop_front_locked(state)
+    }
+
+    /// Pops the front without waiting
+    pub fn try_pop(&self) -> Option<T> {
+        self.pop_front_locked(self.lock())
+    }
+
+    /// Takes every element out at once, by swapping in an empty list
+    pub fn drain_all(&self) -> LinkedList<T> {
+        let list = std::mem::take(&mut self.lock().list);
+        self.not_full.notify_all();
+
+        list
+    }
+
+    /// Closes the deque, waking up everyone who is waiting.
+    ///
+    /// Pushing fails from now on, while popping goes on until the deque is empty.
+    pub fn close(&self) {
+        self.lock().closed = true;
+        self.not_empty.notify_all();
+        self.not_full.notify_all();
+    }
+
+    fn pop_front_locked(&self, mut state: MutexGuard<'_, State<T>>) -> Option<T> {
+        let data = state.list.pop_front();
+        if data.is_some() {
+            self.not_full.notify_one();
+        }
+
+        data
+    }
+
+    /// Waits until there is room, or None if the deque got closed
+    fn wait_for_room(&self) -> Option<MutexGuard<'_, State<T>>> {
+        let state = self.lock();
+        let state = self.not_full
+            .wait_while(state, |state| !state.closed && self.is_full(state))
+            .unwrap_or_else(|error| error.into_inner());
+
+        if state.closed { None } else { Some(state) }
+    }
+
+    fn is_full(&self, state: &State<T>) -> bool {
+        self.capacity.is_some_and(|capacity| state.list.len() >= capacity)
+    }
+
+    /// Locks the state, a panic elsewhere cannot leave the list itself broken
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        self.state.lock().unwrap_or_else(|error| error.into_inner())
+    }
+}
+
+impl<T> Default for BlockingDeque<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn test_push_pop() {
+        let blocking_deque = BlockingDeque::new();
+        assert_eq!(blocking_deque.try_pop(), None);
+
+        blocking_deque.push_back(42).unwrap();
+        blocking_deque.push_back(666).unwrap();
+        blocking_deque.push_front(1337).unwrap();
+
+        assert_eq!(blocking_deque.len(), 3);
+        assert_eq!(blocking_deque.try_pop(), Some(1337));
+        assert_eq!(blocking_deque.pop_front_blocking(), Some(42));
+        assert_eq!(blocking_deque.pop_front_timeout(Duration::from_millis(10)), Some(666));
+        assert_eq!(blocking_deque.pop_front_timeout(Duration::from_millis(10)), None);
+        assert!(blocking_deque.is_empty());
+    }
+
+    #[test]
+    fn test_producer_consumer() {
+        let blocking_deque = Arc::new(BlockingDeque::with_capacity(4));
+
+        let producers = (0 .. 4).map(|i| {
+            let blocking_deque = blocking_deque.clone();
+            thread::spawn(move || {
+                (0 .. 1000).for_each(|j| blocking_deque.push_back(i * 1000 + j).unwrap());
+            })
+        }).collect::<Vec<_>>();
+
+        let consumer = {
+            let blocking_deque = blocking_deque.clone();
+            thread::spawn(move || {
+                let mut received = Vec::new();
+                while let Some(data) = blocking_deque.pop_front_blocking() {
+                    assert!(blocking_deque.len() <= 4);
+                    received.push(data);
+                }
+
+                received
+            })
+        };
+
+        producers.into_iter().for_each(|producer| producer.join().unwrap());
+        blocking_deque.close();
+
+        let mut received = consumer.join().unwrap();
+        received.sort();
+        assert_eq!(received, (0 .. 4000).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_backpressure() {
+        let blocking_deque = Arc::new(BlockingDeque::with_capacity(1));
+        blocking_deque.push_back(1337).unwrap();
+        assert_eq!(blocking_deque.try_push_back(42), Err(42));
+
+        // Blocks until the consumer makes room
+        let producer = {
+            let blocking_deque = blocking_deque.clone();
+            thread::spawn(move || blocking_deque.push_back(42))
+        };
+
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(blocking_deque.len(), 1);
+        assert_eq!(blocking_deque.pop_front_blocking(), Some(1337));
+
+        producer.join().unwrap().unwrap();
+        assert_eq!(blocking_deque.try_pop(), Some(42));
+    }
+
+    #[test]
+    fn test_close() {
+        let blocking_deque = Arc::new(BlockingDeque::new());
+
+        let consumer = {
+            let blocking_deque = blocking_deque.clone();
+            thread::spawn(move || blocking_deque.pop_front_blocking())
+        };
+
+        thread::sleep(Duration::from_millis(20));
+        blocking_deque.close();
+        assert_eq!(consumer.join().unwrap(), None::<u32>);
+
+        assert!(blocking_deque.is_closed());
+        assert_eq!(blocking_deque.push_back(1337), Err(1337));
+        assert_eq!(blocking_deque.push_front(42), Err(42));
+        assert_eq!(blocking_deque.try_push_back(666), Err(666));
+    }
+
+    #[test]
+    fn test_close_keeps_elements() {
+        let blocking_deque = BlockingDeque::with_capacity(2);
+        blocking_deque.push_back(1337).unwrap();
+        blocking_deque.close();
+
+        assert_eq!(blocking_deque.pop_front_blocking(), Some(1337));
+        assert_eq!(blocking_deque.pop_front_blocking(), None);
+    }
+
+    #[test]
+    fn test_drain_all() {
+        let blocking_deque = BlockingDeque::with_capacity(3);
+        (0 .. 3).for_each(|i| blocking_deque.push_back(i).unwrap());
+
+        let drained = blocking_deque.drain_all();
+        assert_eq!(drained.iter().collect::<Vec<_>>(), vec![&0, &1, &2]);
+        assert!(blocking_deque.is_empty());
+
+        blocking_deque.try_push_back(1337).unwrap();
+        assert_eq!(blocking_deque.len(), 1);
+    }
+}
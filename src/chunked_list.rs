@@ -0,0 +1,820 @@
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::iter::FromIterator;
+use std::{fmt, ptr, slice};
+
+use crate::linked_list::LinkedList;
+use crate::node::{NodeLink, NodeLinkSome};
+use crate::{next_unsafe, previous_unsafe};
+
+/// Number of elements each node of a `ChunkedList` holds
+pub const CHUNK_CAPACITY: usize = 16;
+
+/// Neighbouring chunks are merged when they fit in this many elements, leaving
+/// room for a few inserts before the merged chunk has to split again
+const MERGE_THRESHOLD: usize = CHUNK_CAPACITY * 3 / 4;
+
+/// Fixed capacity array, with its elements packed at the start
+struct Chunk<T> {
+    length: usize,
+    data: [MaybeUninit<T>; CHUNK_CAPACITY],
+}
+
+impl<T> Chunk<T> {
+    fn new() -> Self {
+        Self { length: 0, data: [const { MaybeUninit::uninit() }; CHUNK_CAPACITY] }
+    }
+
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn is_full(&self) -> bool {
+        self.length == CHUNK_CAPACITY
+    }
+
+    fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.data.as_ptr() as *const T, self.length) }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.data.as_mut_ptr() as *mut T, self.length) }
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut T {
+        self.data.as_mut_ptr() as *mut T
+    }
+
+    fn insert(&mut self, index: usize, data: T) {
+        assert!(index <= self.length && !self.is_full());
+
+        unsafe {
+            // Shift the rest one place back to make room
+            let p = self.as_mut_ptr().add(index);
+            ptr::copy(p, p.add(1), self.length - index);
+            ptr::write(p, data);
+        }
+
+        self.length += 1;
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.length);
+
+        unsafe {
+            // Move the element out, and shift the rest one place forward over it
+            let p = self.as_mut_ptr().add(index);
+            let data = ptr::read(p);
+            ptr::copy(p.add(1), p, self.length - index - 1);
+            self.length -= 1;
+
+            data
+        }
+    }
+
+    /// Moves the elements from `at` onwards to a new chunk
+    fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.length);
+
+        let mut other = Chunk::new();
+        unsafe {
+            ptr::copy_nonoverlapping(self.as_mut_ptr().add(at), other.as_mut_ptr(), self.length - at);
+        }
+        other.length = self.length - at;
+        self.length = at;
+
+        other
+    }
+
+    /// Moves all elements of `other` to the back of this chunk
+    fn append(&mut self, other: &mut Self) {
+        assert!(self.length + other.length <= CHUNK_CAPACITY);
+
+        unsafe {
+            ptr::copy_nonoverlapping(other.as_mut_ptr(), self.as_mut_ptr().add(self.length), other.length);
+        }
+        self.length += other.length;
+        other.length = 0;
+    }
+}
+
+impl<T> Drop for Chunk<T> {
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) };
+    }
+}
+
+type ChunkLink<T> = NodeLink<Chunk<T>>;
+type ChunkLinkSome<T> = NodeLinkSome<Chunk<T>>;
+
+/// Position of an element, as its chunk and the offset in it. The chunk is None past the end.
+type Position<T> = (ChunkLink<T>, usize);
+
+/// Gets the chunk of a node, for as long as the caller holds on to the list
+fn chunk<'a, T>(node: ChunkLinkSome<T>) -> &'a Chunk<T> {
+    unsafe { &(*node.as_ptr()).data }
+}
+
+fn chunk_mut<'a, T>(node: ChunkLinkSome<T>) -> &'a mut Chunk<T> {
+    unsafe { &mut (*node.as_ptr()).data }
+}
+
+/// Gets an element without going through a reference to its whole chunk, which
+/// would overlap the elements already handed out by `IterMut`
+fn element_mut<'a, T>(node: ChunkLinkSome<T>, offset: usize) -> &'a mut T {
+    unsafe {
+        let chunk = ptr::addr_of_mut!((*node.as_ptr()).data);
+        &mut *ptr::addr_of_mut!((*chunk).data).cast::<T>().add(offset)
+    }
+}
+
+fn chunk_len<T>(node: ChunkLinkSome<T>) -> usize {
+    unsafe { ptr::addr_of!((*node.as_ptr()).data.length).read() }
+}
+
+/// An unrolled linked list, where each node holds up to `CHUNK_CAPACITY` elements.
+///
+/// Fewer, fuller nodes mean fewer pointers to chase when iterating.
+pub struct ChunkedList<T> {
+    chunks: LinkedList<Chunk<T>>,
+    length: usize,
+}
+
+impl<T> ChunkedList<T> {
+    pub fn new() -> Self {
+        Self { chunks: LinkedList::new(), length: 0 }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.chunks.head_link().map(|node| &chunk(node).as_slice()[0])
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.chunks.foot_link().map(|node| {
+            let chunk = chunk(node);
+            &chunk.as_slice()[chunk.len() - 1]
+        })
+    }
+
+    pub fn push_front(&mut self, data: T) {
+        let head = self.chunks.head_link();
+        self.insert_at((head, 0), data);
+    }
+
+    pub fn push_back(&mut self, data: T) {
+        self.insert_at((None, 0), data);
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.chunks.head_link().map(|node| self.remove_at(node, 0).0)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.chunks.foot_link().map(|node| {
+            let offset = chunk(node).len() - 1;
+            self.remove_at(node, offset).0
+        })
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match self.locate(index) {
+            (Some(node), offset) => Some(&chunk(node).as_slice()[offset]),
+            _ => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match self.locate(index) {
+            (Some(node), offset) => Some(&mut chunk_mut(node).as_mut_slice()[offset]),
+            _ => None,
+        }
+    }
+
+    /// Inserts `data` at `index`, shifting everything after it back.
+    ///
+    /// # Panics
+    /// If `index` is greater than the length of the list.
+    pub fn insert(&mut self, index: usize, data: T) {
+        assert!(index <= self.length, "insert: index ({index}) > len ({})", self.length);
+
+        let position = self.locate(index);
+        self.insert_at(position, data);
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        match self.locate(index) {
+            (Some(node), offset) => Some(self.remove_at(node, offset).0),
+            _ => None,
+        }
+    }
+
+    pub fn iter(&'_ self) -> Iter<'_, T> {
+        Iter {
+            front: [].iter(),
+            back: [].iter(),
+            next_front_chunk: self.chunks.head_link(),
+            next_back_chunk: self.chunks.foot_link(),
+            length: self.length,
+        }
+    }
+
+    pub fn iter_mut(&'_ mut self) -> IterMut<'_, T> {
+        IterMut {
+            front: (self.chunks.head_link(), 0),
+            back: (self.chunks.foot_link(), self.chunks.foot_link().map_or(0, |node| chunk(node).len())),
+            length: self.length,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Gets a cursor in front of the first element
+    pub fn cursor_mut(&mut self) -> Cursor<'_, T> {
+        Cursor {
+            next: (self.chunks.head_link(), 0),
+            list: self,
+        }
+    }
+
+    /// Finds the position of the element at `index`, walking the chunks from the nearer end
+    fn locate(&self, index: usize) -> Position<T> {
+        if index >= self.length {
+            return (None, 0);
+        }
+
+        if index < self.length / 2 {
+            let mut skipped = 0;
+            let mut current = self.chunks.head_link();
+
+            while let Some(node) = current {
+                let length = chunk(node).len();
+                if index < skipped + length {
+                    return (Some(node), index - skipped);
+                }
+
+                skipped += length;
+                current = next_unsafe!(node);
+            }
+        }
+        else {
+            // Count down the elements from the back instead
+            let mut remaining = self.length;
+            let mut current = self.chunks.foot_link();
+
+            while let Some(node) = current {
+                remaining -= chunk(node).len();
+                if index >= remaining {
+                    return (Some(node), index - remaining);
+                }
+
+                current = previous_unsafe!(node);
+            }
+        }
+
+        unreachable!("chunk lengths add up to the list length")
+    }
+
+    /// Inserts `data` in front of the element at `position`, splitting its chunk if full.
+    ///
+    /// Returns the position of the inserted element.
+    fn insert_at(&mut self, position: Position<T>, data: T) -> (ChunkLinkSome<T>, usize) {
+        self.length += 1;
+
+        let (node, offset) = match position {
+            (Some(node), offset) => (node, offset),
+            (None, _) => match self.chunks.foot_link() {
+                // Past the end is the back of the last chunk
+                Some(foot) => (foot, chunk(foot).len()),
+                None => {
+                    self.chunks.push_back(Chunk::new());
+                    (self.chunks.foot_link().unwrap(), 0)
+                }
+            },
+        };
+
+        if !chunk(node).is_full() {
+            chunk_mut(node).insert(offset, data);
+            return (node, offset);
+        }
+
+        // In front of a full chunk there may be room at the back of the previous one
+        if offset == 0 {
+            if let Some(previous) = previous_unsafe!(node) {
+                if !chunk(previous).is_full() {
+                    let offset = chunk(previous).len();
+                    chunk_mut(previous).insert(offset, data);
+                    return (previous, offset);
+                }
+            }
+        }
+
+        // At either end of a full chunk, start a new chunk rather than splitting, so
+        // pushing to the front or back of the list keeps the chunks full
+        if offset == 0 || offset == CHUNK_CAPACITY {
+            let mut new_chunk = Chunk::new();
+            new_chunk.insert(0, data);
+            let new_node = LinkedList::new_node_link(new_chunk);
+
+            if offset == 0 {
+                self.chunks.link_node(previous_unsafe!(node), new_node, Some(node));
+            }
+            else {
+                self.chunks.link_node(Some(node), new_node, next_unsafe!(node));
+            }
+
+            return (new_node, 0);
+        }
+
+        // Split the full chunk in half, and insert into whichever half it belongs in
+        let half = CHUNK_CAPACITY / 2;
+        let new_node = LinkedList::new_node_link(chunk_mut(node).split_off(half));
+        self.chunks.link_node(Some(node), new_node, next_unsafe!(node));
+
+        if offset <= half {
+            chunk_mut(node).insert(offset, data);
+            (node, offset)
+        }
+        else {
+            chunk_mut(new_node).insert(offset - half, data);
+            (new_node, offset - half)
+        }
+    }
+
+    /// Removes the element at `offset` in the chunk of `node`, merging chunks that got small.
+    ///
+    /// Returns the element and the position of the one that was after it.
+    fn remove_at(&mut self, node: ChunkLinkSome<T>, offset: usize) -> (T, Position<T>) {
+        self.length -= 1;
+
+        let data = chunk_mut(node).remove(offset);
+        let next_node = next_unsafe!(node);
+
+        if chunk(node).len() == 0 {
+            unsafe {
+                self.chunks.unlink_node(node);
+                drop(Box::from_raw(node.as_ptr()));
+            }
+
+            return (data, (next_node, 0));
+        }
+
+        let mut position = if offset < chunk(node).len() { (Some(node), offset) } else { (next_node, 0) };
+
+        if let Some(next_node) = next_node {
+            let length = chunk(node).len();
+
+            if length + chunk(next_node).len() <= MERGE_THRESHOLD {
+                chunk_mut(node).append(chunk_mut(next_node));
+
+                unsafe {
+                    self.chunks.unlink_node(next_node);
+                    drop(Box::from_raw(next_node.as_ptr()));
+                }
+
+                // The next element may have moved into this chunk
+                if position.0 == Some(next_node) {
+                    position = (Some(node), length + position.1);
+                }
+            }
+        }
+
+        (data, position)
+    }
+}
+
+impl<T> Default for ChunkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialEq> PartialEq for ChunkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ChunkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> From<Vec<T>> for ChunkedList<T> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect::<ChunkedList<T>>()
+    }
+}
+
+impl<T> FromIterator<T> for ChunkedList<T> {
+    fn from_iter<U: IntoIterator<Item = T>>(iter: U) -> Self {
+        let mut list = ChunkedList::<T>::new();
+        iter.into_iter().for_each(|x| list.push_back(x));
+
+        list
+    }
+}
+
+/// A cursor in between two elements of a `ChunkedList`, like `linked_list::Cursor`
+pub struct Cursor<'a, T> {
+    next: Position<T>,
+    list: &'a mut ChunkedList<T>,
+}
+
+impl<'a, T> Cursor<'a, T> {
+    pub fn next_data(&self) -> Option<&T> {
+        match self.next {
+            (Some(node), offset) => Some(&chunk(node).as_slice()[offset]),
+            _ => None,
+        }
+    }
+
+    pub fn previous_data(&self) -> Option<&T> {
+        match self.previous() {
+            (Some(node), offset) => Some(&chunk(node).as_slice()[offset]),
+            _ => None,
+        }
+    }
+
+    pub fn move_next(&mut self) {
+        if let (Some(node), offset) = self.next {
+            self.next = if offset + 1 < chunk(node).len() {
+                (Some(node), offset + 1)
+            }
+            else {
+                (next_unsafe!(node), 0)
+            };
+        }
+    }
+
+    pub fn move_previous(&mut self) {
+        if let previous @ (Some(_), _) = self.previous() {
+            self.next = previous;
+        }
+    }
+
+    /// Inserts at the cursor, the cursor ends up right after the new element
+    pub fn push(&mut self, data: T) {
+        let (node, offset) = self.list.insert_at(self.next, data);
+
+        self.next = (Some(node), offset);
+        self.move_next();
+    }
+
+    pub fn remove_next(&mut self) -> Option<T> {
+        match self.next {
+            (Some(node), offset) => {
+                let (data, next) = self.list.remove_at(node, offset);
+                self.next = next;
+
+                Some(data)
+            }
+            _ => None,
+        }
+    }
+
+    fn previous(&self) -> Position<T> {
+        match self.next {
+            (Some(node), offset) if offset > 0 => (Some(node), offset - 1),
+            (node, _) => {
+                // The last element of the chunk before, or of the list when past the end
+                let previous = match node {
+                    Some(node) => previous_unsafe!(node),
+                    None => self.list.chunks.foot_link(),
+                };
+
+                (previous, previous.map_or(0, |node| chunk(node).len() - 1))
+            }
+        }
+    }
+}
+
+pub struct Iter<'a, T> {
+    front: slice::Iter<'a, T>,
+    back: slice::Iter<'a, T>,
+    next_front_chunk: ChunkLink<T>,
+    next_back_chunk: ChunkLink<T>,
+    length: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Front and back may be in the same chunk, so stop on the count
+        if self.length == 0 {
+            return None;
+        }
+        self.length -= 1;
+
+        loop {
+            if let Some(data) = self.front.next() {
+                return Some(data);
+            }
+
+            match self.next_front_chunk {
+                Some(node) => {
+                    self.front = chunk(node).as_slice().iter();
+                    self.next_front_chunk = next_unsafe!(node);
+                }
+                // Only the back slice has elements left
+                None => return self.back.next(),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.length, Some(self.length))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {
+    fn len(&self) -> usize {
+        self.length
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.length == 0 {
+            return None;
+        }
+        self.length -= 1;
+
+        loop {
+            if let Some(data) = self.back.next_back() {
+                return Some(data);
+            }
+
+            match self.next_back_chunk {
+                Some(node) => {
+                    self.back = chunk(node).as_slice().iter();
+                    self.next_back_chunk = previous_unsafe!(node);
+                }
+                None => return self.front.next_back(),
+            }
+        }
+    }
+}
+
+pub struct IterMut<'a, T> {
+    // Position of the next element from the front, and one past the next from the back
+    front: Position<T>,
+    back: Position<T>,
+    length: usize,
+    _phantom: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.length == 0 {
+            return None;
+        }
+        self.length -= 1;
+
+        let (node, offset) = self.front;
+        let node = node?;
+
+        self.front = if offset + 1 < chunk_len(node) { (Some(node), offset + 1) } else { (next_unsafe!(node), 0) };
+
+        // Hand out one element at a time, never overlapping slices from both ends
+        Some(element_mut(node, offset))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.length, Some(self.length))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {
+    fn len(&self) -> usize {
+        self.length
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.length == 0 {
+            return None;
+        }
+        self.length -= 1;
+
+        let (mut node, mut end) = self.back;
+        if end == 0 {
+            node = node.and_then(|node| previous_unsafe!(node));
+            end = node.map_or(0, chunk_len);
+        }
+
+        let node = node?;
+        self.back = (Some(node), end - 1);
+
+        Some(element_mut(node, end - 1))
+    }
+}
+
+pub struct IntoIter<T> {
+    list: ChunkedList<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T> IntoIterator for ChunkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {
+    fn len(&self) -> usize {
+        self.list.length
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty() {
+        let chunked_list = ChunkedList::<u32>::new();
+        assert!(chunked_list.is_empty());
+        assert_eq!(chunked_list.len(), 0);
+        assert_eq!(chunked_list.front(), None);
+        assert_eq!(chunked_list.back(), None);
+        assert_eq!(chunked_list.get(0), None);
+        assert_eq!(chunked_list.iter().next(), None);
+    }
+
+    #[test]
+    fn test_push_pop() {
+        let mut chunked_list = ChunkedList::new();
+        for i in 0 .. 100 {
+            chunked_list.push_back(i);
+            chunked_list.push_front(-i);
+        }
+
+        assert_eq!(chunked_list.len(), 200);
+        assert_eq!(chunked_list.front(), Some(&-99));
+        assert_eq!(chunked_list.back(), Some(&99));
+
+        for i in (0 .. 100).rev() {
+            assert_eq!(chunked_list.pop_back(), Some(i));
+            assert_eq!(chunked_list.pop_front(), Some(-i));
+        }
+
+        assert_eq!(chunked_list.pop_back(), None);
+        assert!(chunked_list.is_empty());
+        assert!(chunked_list.chunks.is_empty());
+    }
+
+    #[test]
+    fn test_push_fills_chunks() {
+        let mut chunked_list = ChunkedList::new();
+        (0 .. 10 * CHUNK_CAPACITY).for_each(|i| chunked_list.push_back(i));
+        assert_eq!(chunked_list.chunks.len(), 10);
+        assert!(chunked_list.chunks.iter().all(|chunk| chunk.is_full()));
+
+        let mut chunked_list = ChunkedList::new();
+        (0 .. 10 * CHUNK_CAPACITY + 1).for_each(|i| chunked_list.push_front(i));
+        assert_eq!(chunked_list.chunks.len(), 11);
+        assert_eq!(chunked_list.chunks.iter().filter(|chunk| chunk.is_full()).count(), 10);
+        assert_eq!(chunked_list.front(), Some(&(10 * CHUNK_CAPACITY)));
+        assert_eq!(chunked_list.back(), Some(&0));
+    }
+
+    #[test]
+    fn test_insert_get_remove() {
+        let mut chunked_list = ChunkedList::new();
+        let mut vec = Vec::new();
+
+        // Keep inserting in the middle, so chunks have to split
+        for i in 0 .. 200 {
+            chunked_list.insert(i / 2, i);
+            vec.insert(i / 2, i);
+        }
+
+        assert_eq!(chunked_list.len(), 200);
+        assert!(chunked_list.chunks.len() > 200 / CHUNK_CAPACITY);
+        assert_eq!(chunked_list.iter().copied().collect::<Vec<_>>(), vec);
+        assert_eq!(chunked_list.iter().rev().copied().collect::<Vec<_>>(), vec.iter().rev().copied().collect::<Vec<_>>());
+        (0 .. 200).for_each(|i| assert_eq!(chunked_list.get(i), Some(&vec[i])));
+        assert_eq!(chunked_list.get(200), None);
+
+        *chunked_list.get_mut(42).unwrap() = 1337;
+        vec[42] = 1337;
+
+        // And remove until chunks have to merge
+        for i in (0 .. 190).map(|i| (i * 7) % (200 - i)) {
+            assert_eq!(chunked_list.remove(i), Some(vec.remove(i)));
+        }
+
+        assert_eq!(chunked_list.len(), 10);
+        assert!(chunked_list.chunks.len() <= 2);
+        assert_eq!(chunked_list.iter().copied().collect::<Vec<_>>(), vec);
+        assert_eq!(chunked_list.remove(10), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_insert_out_of_bounds() {
+        let mut chunked_list = ChunkedList::from(vec![1337, 42]);
+        chunked_list.insert(3, 666);
+    }
+
+    #[test]
+    fn test_iter_double_ended() {
+        let chunked_list = (0 .. 40).collect::<ChunkedList<_>>();
+
+        let mut chunked_list_iter = chunked_list.iter();
+        assert_eq!(chunked_list_iter.len(), 40);
+        assert_eq!(chunked_list_iter.next(), Some(&0));
+        assert_eq!(chunked_list_iter.next_back(), Some(&39));
+        assert_eq!(chunked_list_iter.by_ref().take(37).count(), 37);
+        assert_eq!(chunked_list_iter.next_back(), Some(&38));
+        assert_eq!(chunked_list_iter.next(), None);
+        assert_eq!(chunked_list_iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut chunked_list = (0 .. 40).collect::<ChunkedList<_>>();
+        chunked_list.iter_mut().take(20).for_each(|x| *x += 100);
+        chunked_list.iter_mut().rev().take(20).for_each(|x| *x += 1000);
+
+        let expected = (0 .. 20).map(|x| x + 100).chain((20 .. 40).map(|x| x + 1000)).collect::<Vec<_>>();
+        assert_eq!(chunked_list.iter().copied().collect::<Vec<_>>(), expected);
+
+        let mut chunked_list_iter = chunked_list.iter_mut();
+        assert_eq!(chunked_list_iter.next_back(), Some(&mut 1039));
+        assert_eq!(chunked_list_iter.len(), 39);
+        assert_eq!(chunked_list_iter.count(), 39);
+    }
+
+    #[test]
+    fn test_cursor() {
+        let mut chunked_list = ChunkedList::from(vec![1337, 666]);
+
+        let mut cursor = chunked_list.cursor_mut();
+        assert_eq!(cursor.previous_data(), None);
+        assert_eq!(cursor.next_data(), Some(&1337));
+        cursor.move_next();
+
+        // Enough pushes to split the chunk under the cursor
+        for i in 0 .. 40 {
+            cursor.push(i);
+            assert_eq!(cursor.previous_data(), Some(&i));
+            assert_eq!(cursor.next_data(), Some(&666));
+        }
+
+        cursor.move_previous();
+        assert_eq!(cursor.next_data(), Some(&39));
+        assert_eq!(cursor.remove_next(), Some(39));
+        assert_eq!(cursor.next_data(), Some(&666));
+        cursor.move_next();
+        assert_eq!(cursor.next_data(), None);
+        assert_eq!(cursor.previous_data(), Some(&666));
+        assert_eq!(cursor.remove_next(), None);
+
+        let expected = std::iter::once(1337).chain(0 .. 39).chain(std::iter::once(666)).collect::<Vec<_>>();
+        assert_eq!(chunked_list.len(), 41);
+        assert_eq!(chunked_list.iter().copied().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_drop() {
+        let chunked_list = (0 .. 100).map(|i| i.to_string()).collect::<ChunkedList<_>>();
+
+        let mut chunked_list_iter = chunked_list.into_iter();
+        assert_eq!(chunked_list_iter.next().unwrap().as_str(), "0");
+        assert_eq!(chunked_list_iter.next_back().unwrap().as_str(), "99");
+        drop(chunked_list_iter);
+    }
+}
@@ -0,0 +1,497 @@
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::iter::FromIterator;
+
+use crate::linked_list::LinkedList;
+use crate::node::{NodeLink, NodeLinkSome};
+use crate::{next_unsafe, next, previous, data_unsafe, previous_unsafe};
+
+/// A doubly linked ring, where the node after the back is the front again
+#[derive(Debug)]
+pub struct CircularList<T> {
+    head: NodeLink<T>,
+    length: usize,
+}
+
+impl<T> CircularList<T> {
+    pub fn new() -> Self {
+        Self { head: None, length: 0 }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.head.map(|node| data_unsafe!(node))
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.foot().map(|node| data_unsafe!(node))
+    }
+
+    pub fn push_front(&mut self, data: T) {
+        self.push_back(data);
+
+        // The ring is the same, the new node is just in front of head
+        self.head = self.foot();
+    }
+
+    pub fn push_back(&mut self, data: T) {
+        let new_node = LinkedList::new_node_link(data);
+
+        match self.head {
+            // Right behind head is the back of the ring
+            Some(head) => unsafe { self.link_node(previous!(head).unwrap(), new_node, head) },
+            None => unsafe { self.link_single(new_node) },
+        }
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.map(|node| unsafe { self.unlink_node(node) })
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.foot().map(|node| unsafe { self.unlink_node(node) })
+    }
+
+    /// Moves the front of the ring `n` places forward, or backward if `n` is negative.
+    ///
+    /// Goes the shorter way around, so this is O(min(n, len - n)) modulo the length.
+    pub fn rotate(&mut self, n: isize) {
+        self.head = self.head.map(|head| walk(head, n, self.length));
+    }
+
+    pub fn iter(&'_ self) -> Iter<'_, T> {
+        Iter {
+            current_front: self.head,
+            current_back: self.foot(),
+            length: self.length,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Gets a cursor at the front of the ring
+    pub fn cursor_mut(&mut self) -> Cursor<'_, T> {
+        Cursor {
+            current: self.head,
+            list: self,
+        }
+    }
+
+    fn foot(&self) -> NodeLink<T> {
+        self.head.and_then(|head| previous_unsafe!(head))
+    }
+
+    /// Links `node` as the only node of the empty ring.
+    ///
+    /// # Safety
+    /// The ring must be empty.
+    unsafe fn link_single(&mut self, node: NodeLinkSome<T>) {
+        // A ring of one points at itself both ways
+        next!(node) = Some(node);
+        previous!(node) = Some(node);
+
+        self.head = Some(node);
+        self.length = 1;
+    }
+
+    /// Links `node` in between the adjacent `previous` and `next`.
+    ///
+    /// # Safety
+    /// `previous` and `next` must be neighbouring nodes of this ring.
+    unsafe fn link_node(&mut self, previous: NodeLinkSome<T>, node: NodeLinkSome<T>, next: NodeLinkSome<T>) {
+        previous!(node) = Some(previous);
+        next!(node) = Some(next);
+        next!(previous) = Some(node);
+        previous!(next) = Some(node);
+
+        self.length += 1;
+    }
+
+    /// Unlinks `node` and moves its data out.
+    ///
+    /// # Safety
+    /// `node` must be a node of this ring.
+    unsafe fn unlink_node(&mut self, node: NodeLinkSome<T>) -> T {
+        if self.length == 1 {
+            self.head = None;
+        }
+        else {
+            let previous = previous!(node).unwrap();
+            let next = next!(node).unwrap();
+            next!(previous) = Some(next);
+            previous!(next) = Some(previous);
+
+            if self.head == Some(node) {
+                self.head = Some(next);
+            }
+        }
+
+        self.length -= 1;
+
+        Box::from_raw(node.as_ptr()).data
+    }
+}
+
+/// Walks `n` nodes around a ring of `length` nodes, taking the shorter way
+fn walk<T>(mut node: NodeLinkSome<T>, n: isize, length: usize) -> NodeLinkSome<T> {
+    let forward = n.rem_euclid(length as isize) as usize;
+
+    if forward <= length / 2 {
+        (0 .. forward).for_each(|_| node = next_unsafe!(node).unwrap());
+    }
+    else {
+        (forward .. length).for_each(|_| node = previous_unsafe!(node).unwrap());
+    }
+
+    node
+}
+
+impl<T> Default for CircularList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for CircularList<T> {
+    fn from_iter<U: IntoIterator<Item = T>>(iter: U) -> Self {
+        let mut list = CircularList::<T>::new();
+        iter.into_iter().for_each(|x| list.push_back(x));
+
+        list
+    }
+}
+
+impl<T> From<LinkedList<T>> for CircularList<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        let (head, foot, length) = list.into_parts();
+
+        // Close the ring by linking the foot back to the head
+        if let (Some(head), Some(foot)) = (head, foot) {
+            unsafe {
+                next!(foot) = Some(head);
+                previous!(head) = Some(foot);
+            }
+        }
+
+        Self { head, length }
+    }
+}
+
+impl<T> From<CircularList<T>> for LinkedList<T> {
+    fn from(list: CircularList<T>) -> Self {
+        let list = ManuallyDrop::new(list);
+        let foot = list.foot();
+
+        // Open the ring up again between foot and head
+        if let (Some(head), Some(foot)) = (list.head, foot) {
+            unsafe {
+                next!(foot) = None;
+                previous!(head) = None;
+            }
+        }
+
+        unsafe { LinkedList::from_parts(list.head, foot, list.length) }
+    }
+}
+
+impl<T> Drop for CircularList<T> {
+    fn drop(&mut self) {
+        while self.pop_back().is_some() {}
+    }
+}
+
+/// A cursor resting on a node of the ring, wrapping around at both ends
+pub struct Cursor<'a, T> {
+    current: NodeLink<T>,
+    list: &'a mut CircularList<T>,
+}
+
+impl<'a, T> Cursor<'a, T> {
+    pub fn current(&self) -> Option<&T> {
+        self.current.map(|node| data_unsafe!(node))
+    }
+
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        self.current.map(|node| unsafe { &mut (*node.as_ptr()).data })
+    }
+
+    pub fn move_next(&mut self) {
+        self.current = self.current.and_then(|node| next_unsafe!(node));
+    }
+
+    pub fn move_previous(&mut self) {
+        self.current = self.current.and_then(|node| previous_unsafe!(node));
+    }
+
+    /// Moves `n` nodes forward modulo the length of the ring
+    pub fn advance_by(&mut self, n: usize) {
+        let n = (n % self.list.length.max(1)) as isize;
+        self.current = self.current.map(|node| walk(node, n, self.list.length));
+    }
+
+    /// Makes the current node the front of the ring
+    pub fn make_front(&mut self) {
+        self.list.head = self.current;
+    }
+
+    /// Inserts before the current node, or as the current node if the ring is empty
+    pub fn insert_before(&mut self, data: T) {
+        let new_node = LinkedList::new_node_link(data);
+
+        match self.current {
+            // Before the front is the back of the ring, so head stays put
+            Some(current) => unsafe { self.list.link_node(previous!(current).unwrap(), new_node, current) },
+            None => unsafe {
+                self.list.link_single(new_node);
+                self.current = Some(new_node);
+            },
+        }
+    }
+
+    /// Inserts after the current node, or as the current node if the ring is empty
+    pub fn insert_after(&mut self, data: T) {
+        let new_node = LinkedList::new_node_link(data);
+
+        match self.current {
+            Some(current) => unsafe { self.list.link_node(current, new_node, next!(current).unwrap()) },
+            None => unsafe {
+                self.list.link_single(new_node);
+                self.current = Some(new_node);
+            },
+        }
+    }
+
+    /// Removes the current node, the cursor moves on to the next one
+    pub fn remove_current(&mut self) -> Option<T> {
+        self.current.map(|node| unsafe {
+            self.current = if self.list.length == 1 { None } else { next!(node) };
+
+            self.list.unlink_node(node)
+        })
+    }
+}
+
+pub struct Iter<'a, T> {
+    current_front: NodeLink<T>,
+    current_back: NodeLink<T>,
+    length: usize,
+    _phantom: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Go around only once
+        if self.length == 0 {
+            return None;
+        }
+
+        self.current_front.map(|node| {
+            self.current_front = next_unsafe!(node);
+            self.length -= 1;
+
+            data_unsafe!(node)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.length, Some(self.length))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {
+    fn len(&self) -> usize {
+        self.length
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.length == 0 {
+            return None;
+        }
+
+        self.current_back.map(|node| {
+            self.current_back = previous_unsafe!(node);
+            self.length -= 1;
+
+            data_unsafe!(node)
+        })
+    }
+}
+
+pub struct IntoIter<T> {
+    list: CircularList<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T> IntoIterator for CircularList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {
+    fn len(&self) -> usize {
+        self.list.length
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty() {
+        let circular_list = CircularList::<u32>::new();
+        assert!(circular_list.is_empty());
+        assert_eq!(circular_list.len(), 0);
+        assert_eq!(circular_list.front(), None);
+        assert_eq!(circular_list.back(), None);
+        assert_eq!(circular_list.iter().next(), None);
+    }
+
+    #[test]
+    fn test_push_pop() {
+        let mut circular_list = CircularList::new();
+        circular_list.push_back(42);
+        circular_list.push_back(666);
+        circular_list.push_front(1337);
+
+        assert_eq!(circular_list.len(), 3);
+        assert_eq!(circular_list.front(), Some(&1337));
+        assert_eq!(circular_list.back(), Some(&666));
+        assert_eq!(circular_list.iter().collect::<Vec<_>>(), vec![&1337, &42, &666]);
+        assert_eq!(circular_list.iter().rev().collect::<Vec<_>>(), vec![&666, &42, &1337]);
+
+        assert_eq!(circular_list.pop_back(), Some(666));
+        assert_eq!(circular_list.pop_front(), Some(1337));
+        assert_eq!(circular_list.pop_front(), Some(42));
+        assert_eq!(circular_list.pop_front(), None);
+        assert!(circular_list.is_empty());
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut circular_list = (0..5).collect::<CircularList<_>>();
+
+        circular_list.rotate(2);
+        assert_eq!(circular_list.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4, 0, 1]);
+
+        circular_list.rotate(-3);
+        assert_eq!(circular_list.iter().copied().collect::<Vec<_>>(), vec![4, 0, 1, 2, 3]);
+
+        circular_list.rotate(11);
+        assert_eq!(circular_list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+        assert_eq!(circular_list.back(), Some(&4));
+    }
+
+    #[test]
+    fn test_cursor_wraps() {
+        let mut circular_list = CircularList::from_iter(vec![1337, 42, 666]);
+
+        let mut cursor = circular_list.cursor_mut();
+        assert_eq!(cursor.current(), Some(&1337));
+        cursor.move_previous();
+        assert_eq!(cursor.current(), Some(&666));
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&42));
+
+        cursor.advance_by(7);
+        assert_eq!(cursor.current(), Some(&666));
+        cursor.advance_by(3);
+        assert_eq!(cursor.current(), Some(&666));
+
+        *cursor.current_mut().unwrap() += 1;
+        cursor.make_front();
+
+        assert_eq!(circular_list.iter().copied().collect::<Vec<_>>(), vec![667, 1337, 42]);
+    }
+
+    #[test]
+    fn test_cursor_insert_remove() {
+        let mut circular_list = CircularList::new();
+
+        let mut cursor = circular_list.cursor_mut();
+        assert_eq!(cursor.remove_current(), None);
+        cursor.insert_after(42);
+        assert_eq!(cursor.current(), Some(&42));
+        cursor.insert_after(666);
+        cursor.insert_before(1337);
+        assert_eq!(cursor.current(), Some(&42));
+
+        assert_eq!(circular_list.len(), 3);
+        assert_eq!(circular_list.iter().copied().collect::<Vec<_>>(), vec![42, 666, 1337]);
+
+        let mut cursor = circular_list.cursor_mut();
+        assert_eq!(cursor.remove_current(), Some(42));
+        assert_eq!(cursor.current(), Some(&666));
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(1337));
+
+        // Removing the back wraps around to the front
+        assert_eq!(cursor.current(), Some(&666));
+        assert_eq!(cursor.remove_current(), Some(666));
+        assert_eq!(cursor.current(), None);
+        assert!(circular_list.is_empty());
+    }
+
+    #[test]
+    fn test_linked_list_conversion() {
+        let linked_list = LinkedList::from(vec![1337, 42, 666]);
+
+        let mut circular_list = CircularList::from(linked_list);
+        assert_eq!(circular_list.len(), 3);
+        circular_list.rotate(1);
+
+        let mut linked_list = LinkedList::from(circular_list);
+        assert_eq!(linked_list.len(), 3);
+        assert_eq!(linked_list.iter().collect::<Vec<_>>(), vec![&42, &666, &1337]);
+        assert_eq!(linked_list.iter().rev().collect::<Vec<_>>(), vec![&1337, &666, &42]);
+        assert_eq!(linked_list.pop_back(), Some(1337));
+
+        let circular_list = CircularList::from(LinkedList::<u32>::new());
+        assert!(circular_list.is_empty());
+        assert!(LinkedList::from(circular_list).is_empty());
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let circular_list = CircularList::from_iter(vec![1337, 42, 666]);
+
+        let mut circular_list_iter = circular_list.into_iter();
+        assert_eq!(circular_list_iter.len(), 3);
+        assert_eq!(circular_list_iter.next_back(), Some(666));
+        assert_eq!(circular_list_iter.next(), Some(1337));
+        assert_eq!(circular_list_iter.next(), Some(42));
+        assert_eq!(circular_list_iter.next(), None);
+    }
+}
@@ -0,0 +1,19 @@
+use std::fmt;
+
+/// The errors of the fallible list operations
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The index was not within the list
+    IndexOutOfBounds { index: usize, len: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::IndexOutOfBounds { index, len } => write!(f, "index {index} out of bounds for list of length {len}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
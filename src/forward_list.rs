@@ -0,0 +1,381 @@
+use std::ptr::NonNull;
+use std::marker::PhantomData;
+use std::iter::FromIterator;
+
+use crate::node::{ForwardNode, ForwardNodeLink, ForwardNodeLinkSome};
+use crate::{next_unsafe, next, data_unsafe};
+
+/// A singly linked list, only linked forward from the front
+#[derive(Debug)]
+pub struct ForwardList<T> {
+    head: ForwardNodeLink<T>,
+    length: usize,
+}
+
+impl<T> ForwardList<T> {
+    pub fn new() -> Self {
+        Self { head: None, length: 0 }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    fn new_node_link(data: T) -> ForwardNodeLinkSome<T> {
+        let new_node = Box::new(ForwardNode::<T>::new(data));
+        unsafe {
+            NonNull::new_unchecked(Box::into_raw(new_node))
+        }
+    }
+
+    pub fn push_front(&mut self, data: T) {
+        self.cursor_mut().insert_after(data);
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.cursor_mut().remove_after()
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.head.map(|node| data_unsafe!(node))
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.head.map(|node| unsafe { &mut (*node.as_ptr()).data })
+    }
+
+    /// Reverses the list by pointing every node back at the one before it
+    pub fn reverse(&mut self) {
+        let mut previous_node: ForwardNodeLink<T> = None;
+        let mut current = self.head;
+
+        while let Some(node) = current {
+            unsafe {
+                current = next!(node);
+                next!(node) = previous_node;
+            }
+            previous_node = Some(node);
+        }
+
+        self.head = previous_node;
+    }
+
+    pub fn iter(&'_ self) -> Iter<'_, T> {
+        Iter {
+            current: self.head,
+            length: self.length,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn iter_mut(&'_ mut self) -> IterMut<'_, T> {
+        IterMut {
+            current: self.head,
+            length: self.length,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Gets a cursor in front of the first node
+    pub fn cursor_mut(&mut self) -> Cursor<'_, T> {
+        Cursor {
+            current: None,
+            list: self,
+        }
+    }
+}
+
+impl<T> Default for ForwardList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialEq> PartialEq for ForwardList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T> FromIterator<T> for ForwardList<T> {
+    fn from_iter<U: IntoIterator<Item = T>>(iter: U) -> Self {
+        let mut list = ForwardList::<T>::new();
+
+        // Append through a cursor, so the order is kept
+        let mut cursor = list.cursor_mut();
+        iter.into_iter().for_each(|x| {
+            cursor.insert_after(x);
+            cursor.move_next();
+        });
+
+        list
+    }
+}
+
+impl<T> Drop for ForwardList<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+/// A forward only cursor, resting on a node or in front of the first one
+pub struct Cursor<'a, T> {
+    // None when in front of the first node
+    current: ForwardNodeLink<T>,
+    list: &'a mut ForwardList<T>,
+}
+
+impl<'a, T> Cursor<'a, T> {
+    pub fn current(&self) -> Option<&T> {
+        self.current.map(|node| data_unsafe!(node))
+    }
+
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        self.current.map(|node| unsafe { &mut (*node.as_ptr()).data })
+    }
+
+    pub fn peek_next(&self) -> Option<&T> {
+        self.next_link().map(|node| data_unsafe!(node))
+    }
+
+    /// Moves to the next node, staying put at the last one
+    pub fn move_next(&mut self) {
+        if let Some(next) = self.next_link() {
+            self.current = Some(next);
+        }
+    }
+
+    pub fn insert_after(&mut self, data: T) {
+        let new_node = ForwardList::new_node_link(data);
+
+        unsafe {
+            next!(new_node) = self.next_link();
+        }
+
+        match self.current {
+            Some(current) => unsafe { next!(current) = Some(new_node) },
+            None => self.list.head = Some(new_node),
+        }
+
+        self.list.length += 1;
+    }
+
+    pub fn remove_after(&mut self) -> Option<T> {
+        self.next_link().map(|node| unsafe {
+            // Skip over the removed node
+            match self.current {
+                Some(current) => next!(current) = next!(node),
+                None => self.list.head = next!(node),
+            }
+
+            self.list.length -= 1;
+
+            Box::from_raw(node.as_ptr()).data
+        })
+    }
+
+    fn next_link(&self) -> ForwardNodeLink<T> {
+        match self.current {
+            Some(current) => next_unsafe!(current),
+            None => self.list.head,
+        }
+    }
+}
+
+pub struct Iter<'a, T> {
+    current: ForwardNodeLink<T>,
+    length: usize,
+    _phantom: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.current.map(|node| {
+            self.current = next_unsafe!(node);
+            self.length -= 1;
+
+            data_unsafe!(node)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.length, Some(self.length))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {
+    fn len(&self) -> usize {
+        self.length
+    }
+}
+
+pub struct IterMut<'a, T> {
+    current: ForwardNodeLink<T>,
+    length: usize,
+    _phantom: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.current.map(|node| unsafe {
+            self.current = next!(node);
+            self.length -= 1;
+
+            &mut (*node.as_ptr()).data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.length, Some(self.length))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {
+    fn len(&self) -> usize {
+        self.length
+    }
+}
+
+pub struct IntoIter<T> {
+    list: ForwardList<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T> IntoIterator for ForwardList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {
+    fn len(&self) -> usize {
+        self.list.length
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forwardlist;
+
+    #[test]
+    fn test_empty() {
+        let forward_list = ForwardList::<u32>::new();
+        assert!(forward_list.is_empty());
+        assert_eq!(forward_list.len(), 0);
+        assert_eq!(forward_list.peek(), None);
+    }
+
+    #[test]
+    fn test_push_front_pop_front() {
+        let mut forward_list = ForwardList::new();
+        forward_list.push_front(String::from("foo"));
+        forward_list.push_front(String::from("bar"));
+        forward_list.push_front(String::from("baz"));
+
+        assert_eq!(forward_list.len(), 3);
+        assert_eq!(forward_list.peek().unwrap().as_str(), "baz");
+        assert_eq!(forward_list.pop_front().unwrap().as_str(), "baz");
+        assert_eq!(forward_list.pop_front().unwrap().as_str(), "bar");
+
+        forward_list.peek_mut().unwrap().push_str("bar");
+        assert_eq!(forward_list.pop_front().unwrap().as_str(), "foobar");
+        assert_eq!(forward_list.pop_front(), None);
+        assert!(forward_list.is_empty());
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut forward_list = ForwardList::from_iter(vec![1337, 42, 666]);
+
+        let mut forward_list_iter = forward_list.iter();
+        assert_eq!(forward_list_iter.len(), 3);
+        assert_eq!(forward_list_iter.next(), Some(&1337));
+        assert_eq!(forward_list_iter.next(), Some(&42));
+        assert_eq!(forward_list_iter.next(), Some(&666));
+        assert_eq!(forward_list_iter.next(), None);
+
+        forward_list.iter_mut().for_each(|x| *x += 1);
+        assert_eq!(forward_list.into_iter().collect::<Vec<_>>(), vec![1338, 43, 667]);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut forward_list = ForwardList::from_iter(vec![1337, 42, 666]);
+        forward_list.reverse();
+
+        assert_eq!(forward_list.len(), 3);
+        assert_eq!(forward_list.iter().collect::<Vec<_>>(), vec![&666, &42, &1337]);
+
+        let mut forward_list = ForwardList::<u32>::new();
+        forward_list.reverse();
+        assert!(forward_list.is_empty());
+    }
+
+    #[test]
+    fn test_cursor() {
+        let mut forward_list = ForwardList::from_iter(vec![1337, 666]);
+
+        let mut cursor = forward_list.cursor_mut();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_next(), Some(&1337));
+
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&1337));
+        cursor.insert_after(42);
+        assert_eq!(cursor.peek_next(), Some(&42));
+
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&666));
+
+        // Stays put at the last node
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&666));
+        assert_eq!(cursor.remove_after(), None);
+        *cursor.current_mut().unwrap() += 1;
+
+        assert_eq!(forward_list.iter().collect::<Vec<_>>(), vec![&1337, &42, &667]);
+
+        let mut cursor = forward_list.cursor_mut();
+        cursor.move_next();
+        assert_eq!(cursor.remove_after(), Some(42));
+        assert_eq!(cursor.remove_after(), Some(667));
+        assert_eq!(cursor.remove_after(), None);
+
+        assert_eq!(forward_list.len(), 1);
+        assert_eq!(forward_list, ForwardList::from_iter(vec![1337]));
+    }
+
+    #[test]
+    fn test_macro() {
+        let forward_list: ForwardList<u32> = forwardlist!();
+        assert!(forward_list.is_empty());
+
+        let forward_list = forwardlist![1337, 42, 666];
+        assert_eq!(forward_list.len(), 3);
+        assert_eq!(forward_list.into_iter().collect::<Vec<_>>(), vec![1337, 42, 666]);
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::VecDeque;
+use std::mem;
+
+use crate::linked_list::LinkedList;
+
+/// Measures the memory a value owns on the heap, for capacity planning.
+///
+/// This is what the value points to, all the way down, but not its own size,
+/// so a `Vec<String>` counts its buffer and every string's buffer.
+/// What the allocator keeps for its own bookkeeping is not counted.
+pub trait HeapSize {
+    fn heap_size(&self) -> usize;
+
+    /// The size of the value itself plus what it owns on the heap
+    fn total_size(&self) -> usize
+    where
+        Self: Sized,
+    {
+        mem::size_of::<Self>() + self.heap_size()
+    }
+}
+
+/// Implements `HeapSize` for types that own nothing on the heap
+macro_rules! impl_heap_size_zero {
+    ($($type:ty),*) => {
+        $(
+            impl HeapSize for $type {
+                fn heap_size(&self) -> usize {
+                    0
+                }
+            }
+        )*
+    };
+}
+
+impl_heap_size_zero!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char, (), &str);
+
+impl HeapSize for String {
+    fn heap_size(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl<T: HeapSize> HeapSize for Box<T> {
+    fn heap_size(&self) -> usize {
+        mem::size_of::<T>() + (**self).heap_size()
+    }
+}
+
+impl<T: HeapSize> HeapSize for Option<T> {
+    fn heap_size(&self) -> usize {
+        self.as_ref().map_or(0, T::heap_size)
+    }
+}
+
+impl<T: HeapSize> HeapSize for Vec<T> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * mem::size_of::<T>() + self.iter().map(T::heap_size).sum::<usize>()
+    }
+}
+
+impl<T: HeapSize> HeapSize for VecDeque<T> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * mem::size_of::<T>() + self.iter().map(T::heap_size).sum::<usize>()
+    }
+}
+
+impl<A: HeapSize, B: HeapSize> HeapSize for (A, B) {
+    fn heap_size(&self) -> usize {
+        self.0.heap_size() + self.1.heap_size()
+    }
+}
+
+impl<T: HeapSize> HeapSize for LinkedList<T> {
+    fn heap_size(&self) -> usize {
+        self.allocated_bytes() + self.iter().map(T::heap_size).sum::<usize>()
+    }
+}
+
+/// How the memory of a `LinkedList` is spent, see `LinkedList::stats`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListStats {
+    pub nodes: usize,
+    /// The size of a node, its element and the two links
+    pub bytes_per_node: usize,
+    /// The size of the element in a node
+    pub element_bytes: usize,
+    /// The bytes of all the nodes, as `LinkedList::allocated_bytes`
+    pub allocated_bytes: usize,
+    /// The part of every node that goes to the links and padding rather than the element,
+    /// from 0 to 1
+    pub overhead_ratio: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::Node;
+
+    #[test]
+    fn test_allocated_bytes() {
+        // The element and two links
+        let node_size = mem::size_of::<Node<u64>>();
+        assert_eq!(node_size, 8 + 2 * mem::size_of::<usize>());
+
+        // Usable in constants too
+        const NODE_SIZE: usize = LinkedList::<u64>::node_size();
+        assert_eq!(NODE_SIZE, node_size);
+
+        let mut linked_list = LinkedList::from(vec![1337u64, 42, 666]);
+        assert_eq!(linked_list.allocated_bytes(), 3 * node_size);
+
+        linked_list.pop_front();
+        assert_eq!(linked_list.allocated_bytes(), 2 * node_size);
+        assert_eq!(LinkedList::<u64>::new().allocated_bytes(), 0);
+    }
+
+    #[test]
+    fn test_heap_size() {
+        let linked_list = LinkedList::from(vec![1337u64, 42]);
+        let node_size = mem::size_of::<Node<u64>>();
+        assert_eq!(linked_list.heap_size(), 2 * node_size);
+        assert_eq!(linked_list.total_size(), mem::size_of::<LinkedList<u64>>() + 2 * node_size);
+
+        let strings = LinkedList::from(vec![String::with_capacity(10), String::from("hej")]);
+        assert_eq!(strings.heap_size(), 2 * mem::size_of::<Node<String>>() + 10 + 3);
+
+        let nested = vec![Some(Box::new(7u32)), None];
+        assert_eq!(nested.heap_size(), nested.capacity() * mem::size_of::<Option<Box<u32>>>() + 4);
+
+        let pairs = LinkedList::from(vec![(1u8, String::from("foo"))]);
+        assert_eq!(pairs.heap_size(), mem::size_of::<Node<(u8, String)>>() + 3);
+    }
+
+    #[test]
+    fn test_stats() {
+        let linked_list = (0 .. 100).collect::<LinkedList<u64>>();
+
+        let stats = linked_list.stats();
+        assert_eq!(stats.nodes, 100);
+        assert_eq!(stats.bytes_per_node, mem::size_of::<Node<u64>>());
+        assert_eq!(stats.element_bytes, 8);
+        assert_eq!(stats.allocated_bytes, 100 * stats.bytes_per_node);
+
+        let link_bytes = (2 * mem::size_of::<usize>()) as f64;
+        assert!((stats.overhead_ratio - link_bytes / (8.0 + link_bytes)).abs() < 1e-9);
+
+        // A zero sized element is all overhead
+        assert_eq!(LinkedList::from(vec![(), ()]).stats().overhead_ratio, 1.0);
+        assert!(format!("{stats:?}").contains("nodes: 100"));
+    }
+}
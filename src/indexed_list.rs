@@ -0,0 +1,586 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+use std::iter::FromIterator;
+use std::fmt;
+
+/// Highest tower a node can get, plenty for any list that fits in memory
+const MAX_LEVEL: usize = 32;
+
+type SkipLink<T> = Option<NonNull<SkipNode<T>>>;
+
+struct Link<T> {
+    next: SkipLink<T>,
+    // Number of nodes this link skips ahead, or when next is None, the number of nodes after it
+    span: usize,
+}
+
+impl<T> Clone for Link<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Link<T> {}
+
+struct SkipNode<T> {
+    data: T,
+    // Only the bottom level links back, for cursors and reverse iteration
+    previous: SkipLink<T>,
+    links: Box<[Link<T>]>,
+}
+
+/// A list with O(log n) positional access, kept as an indexable skip list.
+///
+/// Every link of the skip list knows how many nodes it skips, so `get`, `insert`,
+/// `remove` and `cursor_at` find their index in expected O(log n). Moving a
+/// cursor and editing the element under it is O(1), as the bottom level is an
+/// ordinary doubly linked list. Inserting or removing at a cursor is O(log n),
+/// since the spans of the links passing over it have to be kept up to date.
+pub struct IndexedList<T> {
+    // The links of the head tower, one per level in use
+    head: Vec<Link<T>>,
+    foot: SkipLink<T>,
+    length: usize,
+    random_state: u64,
+}
+
+impl<T> IndexedList<T> {
+    pub fn new() -> Self {
+        Self {
+            head: Vec::new(),
+            foot: None,
+            length: 0,
+            random_state: RandomState::new().build_hasher().finish() | 1,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.node_at(index).map(|node| unsafe { &(*node.as_ptr()).data })
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.node_at(index).map(|node| unsafe { &mut (*node.as_ptr()).data })
+    }
+
+    pub fn push_front(&mut self, data: T) {
+        self.insert(0, data);
+    }
+
+    pub fn push_back(&mut self, data: T) {
+        self.insert(self.length, data);
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.remove(0)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.remove(self.length.checked_sub(1)?)
+    }
+
+    /// Inserts `data` at `index`, shifting everything after it back.
+    ///
+    /// # Panics
+    /// If `index` is greater than the length of the list.
+    pub fn insert(&mut self, index: usize, data: T) {
+        assert!(index <= self.length, "insert: index ({index}) > len ({})", self.length);
+
+        let (mut update, mut rank) = self.predecessors(index);
+        let height = self.random_height();
+
+        // Grow the head tower, its new levels skip the whole list
+        while self.head.len() < height {
+            let level = self.head.len();
+            self.head.push(Link { next: None, span: self.length });
+            update[level] = None;
+            rank[level] = 0;
+        }
+
+        let node = Box::new(SkipNode {
+            data,
+            previous: update[0],
+            links: vec![Link { next: None, span: 0 }; height].into_boxed_slice(),
+        });
+        let node = unsafe { NonNull::new_unchecked(Box::into_raw(node)) };
+
+        for level in 0 .. height {
+            let new_links = unsafe { &mut (*node.as_ptr()).links };
+            let links = self.links_mut(update[level]);
+
+            // The new node takes over the part of the link after it
+            new_links[level].next = links[level].next;
+            new_links[level].span = links[level].span - (rank[0] - rank[level]);
+
+            links[level].next = Some(node);
+            links[level].span = rank[0] - rank[level] + 1;
+        }
+
+        // Links passing over the new node now skip one more
+        for (level, link) in update.iter().enumerate().take(self.head.len()).skip(height) {
+            self.links_mut(*link)[level].span += 1;
+        }
+
+        match unsafe { (*node.as_ptr()).links[0].next } {
+            Some(next) => unsafe { (*next.as_ptr()).previous = Some(node) },
+            None => self.foot = Some(node),
+        }
+
+        self.length += 1;
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.length {
+            return None;
+        }
+
+        let (update, _) = self.predecessors(index);
+        let node = self.links(update[0])[0].next.unwrap();
+        let node_links = unsafe { &(*node.as_ptr()).links };
+
+        for (level, link) in update.iter().enumerate().take(self.head.len()) {
+            let links = self.links_mut(*link);
+
+            if links[level].next == Some(node) {
+                // Skip over the node, and whatever it skipped
+                links[level].span = links[level].span + node_links[level].span - 1;
+                links[level].next = node_links[level].next;
+            }
+            else {
+                links[level].span -= 1;
+            }
+        }
+
+        let previous = unsafe { (*node.as_ptr()).previous };
+        match node_links[0].next {
+            Some(next) => unsafe { (*next.as_ptr()).previous = previous },
+            None => self.foot = previous,
+        }
+
+        // Drop the levels nothing reaches anymore
+        while self.head.last().is_some_and(|link| link.next.is_none()) {
+            self.head.pop();
+        }
+
+        self.length -= 1;
+
+        Some(unsafe { Box::from_raw(node.as_ptr()).data })
+    }
+
+    pub fn iter(&'_ self) -> Iter<'_, T> {
+        Iter {
+            current_front: self.head.first().and_then(|link| link.next),
+            current_back: self.foot,
+            length: self.length,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Gets a cursor on the element at `index`, or past the end if `index` is the length.
+    ///
+    /// # Panics
+    /// If `index` is greater than the length of the list.
+    pub fn cursor_at(&mut self, index: usize) -> Cursor<'_, T> {
+        assert!(index <= self.length, "cursor_at: index ({index}) > len ({})", self.length);
+
+        Cursor {
+            current: self.node_at(index),
+            index,
+            list: self,
+        }
+    }
+
+    fn node_at(&self, index: usize) -> SkipLink<T> {
+        if index >= self.length {
+            return None;
+        }
+
+        // The last node with rank up to index + 1 is the node at index
+        self.predecessors(index + 1).0[0]
+    }
+
+    /// Finds, for every level, the last node with at most `rank` nodes up to and including it.
+    ///
+    /// Returns the nodes, None for the head, and their ranks.
+    fn predecessors(&self, rank: usize) -> ([SkipLink<T>; MAX_LEVEL], [usize; MAX_LEVEL]) {
+        let mut update = [None; MAX_LEVEL];
+        let mut ranks = [0; MAX_LEVEL];
+
+        let mut current: SkipLink<T> = None;
+        let mut traversed = 0;
+
+        for level in (0 .. self.head.len()).rev() {
+            while let Link { next: Some(next), span } = self.links(current)[level] {
+                if traversed + span > rank {
+                    break;
+                }
+
+                traversed += span;
+                current = Some(next);
+            }
+
+            update[level] = current;
+            ranks[level] = traversed;
+        }
+
+        (update, ranks)
+    }
+
+    /// Gets the links of a node, or of the head if None
+    fn links(&self, node: SkipLink<T>) -> &[Link<T>] {
+        match node {
+            Some(node) => unsafe { &(*node.as_ptr()).links },
+            None => &self.head,
+        }
+    }
+
+    fn links_mut(&mut self, node: SkipLink<T>) -> &mut [Link<T>] {
+        match node {
+            Some(node) => unsafe { &mut (*node.as_ptr()).links },
+            None => &mut self.head,
+        }
+    }
+
+    /// Picks a tower height, each level half as likely as the one below
+    fn random_height(&mut self) -> usize {
+        // xorshift64
+        self.random_state ^= self.random_state << 13;
+        self.random_state ^= self.random_state >> 7;
+        self.random_state ^= self.random_state << 17;
+
+        (self.random_state.trailing_ones() as usize + 1).min(MAX_LEVEL)
+    }
+}
+
+impl<T> Default for IndexedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialEq> PartialEq for IndexedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for IndexedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> FromIterator<T> for IndexedList<T> {
+    fn from_iter<U: IntoIterator<Item = T>>(iter: U) -> Self {
+        let mut list = IndexedList::<T>::new();
+        iter.into_iter().for_each(|x| list.push_back(x));
+
+        list
+    }
+}
+
+impl<T> Drop for IndexedList<T> {
+    fn drop(&mut self) {
+        // The bottom level has every node, so free along it
+        let mut current = self.head.first().and_then(|link| link.next);
+        while let Some(node) = current {
+            let node = unsafe { Box::from_raw(node.as_ptr()) };
+            current = node.links[0].next;
+        }
+    }
+}
+
+/// A cursor on an element of an `IndexedList`, which knows its index
+pub struct Cursor<'a, T> {
+    // None when past the end
+    current: SkipLink<T>,
+    index: usize,
+    list: &'a mut IndexedList<T>,
+}
+
+impl<'a, T> Cursor<'a, T> {
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn current(&self) -> Option<&T> {
+        self.current.map(|node| unsafe { &(*node.as_ptr()).data })
+    }
+
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        self.current.map(|node| unsafe { &mut (*node.as_ptr()).data })
+    }
+
+    /// Moves to the next element in O(1), staying put past the end
+    pub fn move_next(&mut self) {
+        if let Some(node) = self.current {
+            self.current = unsafe { (*node.as_ptr()).links[0].next };
+            self.index += 1;
+        }
+    }
+
+    /// Moves to the previous element in O(1), staying put at the front
+    pub fn move_previous(&mut self) {
+        if self.index > 0 {
+            self.current = match self.current {
+                Some(node) => unsafe { (*node.as_ptr()).previous },
+                None => self.list.foot,
+            };
+            self.index -= 1;
+        }
+    }
+
+    /// Inserts in front of the current element, the cursor stays on it
+    pub fn insert_before(&mut self, data: T) {
+        self.list.insert(self.index, data);
+        self.index += 1;
+    }
+
+    /// Removes the current element, the cursor moves on to the next one
+    pub fn remove_current(&mut self) -> Option<T> {
+        self.current.and_then(|node| {
+            self.current = unsafe { (*node.as_ptr()).links[0].next };
+            self.list.remove(self.index)
+        })
+    }
+}
+
+pub struct Iter<'a, T> {
+    current_front: SkipLink<T>,
+    current_back: SkipLink<T>,
+    length: usize,
+    _phantom: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.length == 0 {
+            return None;
+        }
+
+        self.current_front.map(|node| unsafe {
+            self.current_front = (*node.as_ptr()).links[0].next;
+            self.length -= 1;
+
+            &(*node.as_ptr()).data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.length, Some(self.length))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {
+    fn len(&self) -> usize {
+        self.length
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.length == 0 {
+            return None;
+        }
+
+        self.current_back.map(|node| unsafe {
+            self.current_back = (*node.as_ptr()).previous;
+            self.length -= 1;
+
+            &(*node.as_ptr()).data
+        })
+    }
+}
+
+pub struct IntoIter<T> {
+    list: IndexedList<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T> IntoIterator for IndexedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {
+    fn len(&self) -> usize {
+        self.list.length
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl<T> IndexedList<T> {
+        /// Checks every span against the actual distance along the bottom level
+        fn check_spans(&self) {
+            let mut ranks = std::collections::HashMap::new();
+            let mut current = self.head.first().and_then(|link| link.next);
+            let mut rank = 1;
+            while let Some(node) = current {
+                ranks.insert(node, rank);
+                current = unsafe { (*node.as_ptr()).links[0].next };
+                rank += 1;
+            }
+
+            let mut towers = vec![(None, 0)];
+            towers.extend(ranks.iter().map(|(node, rank)| (Some(*node), *rank)));
+
+            for (node, rank) in towers {
+                for link in self.links(node) {
+                    match link.next {
+                        Some(next) => assert_eq!(link.span, ranks[&next] - rank),
+                        None => assert_eq!(link.span, self.length - rank),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut indexed_list = IndexedList::<u32>::new();
+        assert!(indexed_list.is_empty());
+        assert_eq!(indexed_list.get(0), None);
+        assert_eq!(indexed_list.remove(0), None);
+        assert_eq!(indexed_list.pop_back(), None);
+        assert_eq!(indexed_list.iter().next(), None);
+    }
+
+    #[test]
+    fn test_insert_get_remove() {
+        let mut indexed_list = IndexedList::new();
+        let mut vec = Vec::new();
+
+        for i in 0 .. 500 {
+            let index = (i * 7919) % (vec.len() + 1);
+            indexed_list.insert(index, i);
+            vec.insert(index, i);
+        }
+
+        indexed_list.check_spans();
+        assert_eq!(indexed_list.len(), 500);
+        (0 .. 500).for_each(|i| assert_eq!(indexed_list.get(i), Some(&vec[i])));
+        assert_eq!(indexed_list.get(500), None);
+        assert_eq!(indexed_list.iter().copied().collect::<Vec<_>>(), vec);
+        assert_eq!(indexed_list.iter().rev().copied().collect::<Vec<_>>(), vec.iter().rev().copied().collect::<Vec<_>>());
+
+        *indexed_list.get_mut(42).unwrap() = 1337;
+        vec[42] = 1337;
+
+        for i in 0 .. 450 {
+            let index = (i * 104729) % vec.len();
+            assert_eq!(indexed_list.remove(index), Some(vec.remove(index)));
+        }
+
+        indexed_list.check_spans();
+        assert_eq!(indexed_list.len(), 50);
+        assert_eq!(indexed_list.iter().copied().collect::<Vec<_>>(), vec);
+    }
+
+    #[test]
+    fn test_push_pop() {
+        let mut indexed_list = IndexedList::new();
+        indexed_list.push_back(42);
+        indexed_list.push_back(666);
+        indexed_list.push_front(1337);
+
+        assert_eq!(indexed_list.iter().collect::<Vec<_>>(), vec![&1337, &42, &666]);
+        assert_eq!(indexed_list.pop_back(), Some(666));
+        assert_eq!(indexed_list.pop_front(), Some(1337));
+        assert_eq!(indexed_list.pop_front(), Some(42));
+        assert_eq!(indexed_list.pop_front(), None);
+
+        indexed_list.check_spans();
+        assert!(indexed_list.head.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_insert_out_of_bounds() {
+        let mut indexed_list = IndexedList::from_iter(vec![1337, 42]);
+        indexed_list.insert(3, 666);
+    }
+
+    #[test]
+    fn test_cursor() {
+        let mut indexed_list = (0 .. 100).collect::<IndexedList<_>>();
+
+        let mut cursor = indexed_list.cursor_at(50);
+        assert_eq!(cursor.index(), 50);
+        assert_eq!(cursor.current(), Some(&50));
+
+        cursor.move_previous();
+        assert_eq!(cursor.current(), Some(&49));
+        cursor.insert_before(1337);
+        assert_eq!(cursor.index(), 50);
+        assert_eq!(cursor.current(), Some(&49));
+
+        *cursor.current_mut().unwrap() = 666;
+        assert_eq!(cursor.remove_current(), Some(666));
+        assert_eq!(cursor.index(), 50);
+        assert_eq!(cursor.current(), Some(&50));
+
+        let mut cursor = indexed_list.cursor_at(100);
+        assert_eq!(cursor.current(), None);
+        cursor.move_next();
+        assert_eq!(cursor.index(), 100);
+        cursor.move_previous();
+        assert_eq!(cursor.current(), Some(&99));
+
+        let mut cursor = indexed_list.cursor_at(0);
+        cursor.move_previous();
+        assert_eq!(cursor.index(), 0);
+        assert_eq!(cursor.current(), Some(&0));
+
+        indexed_list.check_spans();
+        assert_eq!(indexed_list.get(49), Some(&1337));
+        assert_eq!(indexed_list.get(50), Some(&50));
+        assert_eq!(indexed_list.len(), 100);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let indexed_list = IndexedList::from_iter(vec![1337, 42, 666]);
+
+        let mut indexed_list_iter = indexed_list.into_iter();
+        assert_eq!(indexed_list_iter.len(), 3);
+        assert_eq!(indexed_list_iter.next_back(), Some(666));
+        assert_eq!(indexed_list_iter.next(), Some(1337));
+        assert_eq!(indexed_list_iter.next(), Some(42));
+        assert_eq!(indexed_list_iter.next(), None);
+    }
+}
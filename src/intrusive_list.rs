@@ -0,0 +1,491 @@
+use std::cell::Cell;
+use std::fmt;
+use std::marker::{PhantomData, PhantomPinned};
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+type ItemLink<T> = Option<NonNull<T>>;
+
+/// Hands out the ids lists stamp their items with, 0 meaning not linked
+static NEXT_LIST_ID: AtomicUsize = AtomicUsize::new(1);
+
+/// The link fields a type embeds to be put on an `IntrusiveList`
+pub struct Links<T> {
+    next: Cell<ItemLink<T>>,
+    previous: Cell<ItemLink<T>>,
+    list_id: Cell<usize>,
+    _pin: PhantomPinned,
+}
+
+impl<T> Links<T> {
+    pub const fn new() -> Self {
+        Self {
+            next: Cell::new(None),
+            previous: Cell::new(None),
+            list_id: Cell::new(0),
+            _pin: PhantomPinned,
+        }
+    }
+
+    pub fn is_linked(&self) -> bool {
+        self.list_id.get() != 0
+    }
+}
+
+impl<T> Default for Links<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for Links<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Links").field("linked", &self.is_linked()).finish()
+    }
+}
+
+/// Types that embed `Links` to themselves.
+///
+/// Usually implemented with `intrusive_adapter!`.
+///
+/// # Safety
+/// `links` must always return the same `Links`, embedded in `self`.
+pub unsafe trait Linked: Sized {
+    fn links(&self) -> &Links<Self>;
+}
+
+/// Implements `Linked` for a type, through its `Links` field.
+///
+/// ```
+/// # #[macro_use] extern crate doubly_linked_list;
+/// use std::pin::pin;
+/// use doubly_linked_list::intrusive_list::{IntrusiveList, Links};
+///
+/// struct Task {
+///     id: u32,
+///     links: Links<Task>,
+/// }
+///
+/// intrusive_adapter!(Task, links);
+///
+/// let task = pin!(Task { id: 1337, links: Links::new() });
+///
+/// let mut list = IntrusiveList::new();
+/// list.push_back(task.into_ref());
+/// assert_eq!(list.front().map(|task| task.id), Some(1337));
+/// ```
+#[macro_export]
+macro_rules! intrusive_adapter {
+    ($type:ty, $field:ident) => (
+        unsafe impl $crate::intrusive_list::Linked for $type {
+            fn links(&self) -> &$crate::intrusive_list::Links<Self> {
+                &self.$field
+            }
+        }
+    );
+}
+
+/// A doubly linked list threaded through `Links` embedded in the items themselves.
+///
+/// The list does not own its items, it borrows them pinned for `'a`, so they
+/// can neither move nor be dropped while they are on it.
+pub struct IntrusiveList<'a, T: Linked> {
+    head: ItemLink<T>,
+    foot: ItemLink<T>,
+    length: usize,
+    id: usize,
+    _phantom: PhantomData<&'a T>,
+}
+
+impl<'a, T: Linked> IntrusiveList<'a, T> {
+    pub fn new() -> Self {
+        Self {
+            head: None,
+            foot: None,
+            length: 0,
+            id: NEXT_LIST_ID.fetch_add(1, Ordering::Relaxed),
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn front(&self) -> Option<Pin<&'a T>> {
+        self.head.map(item)
+    }
+
+    pub fn back(&self) -> Option<Pin<&'a T>> {
+        self.foot.map(item)
+    }
+
+    /// # Panics
+    /// If the item is already on a list.
+    pub fn push_front(&mut self, data: Pin<&'a T>) {
+        self.link(None, data, self.head);
+    }
+
+    /// # Panics
+    /// If the item is already on a list.
+    pub fn push_back(&mut self, data: Pin<&'a T>) {
+        self.link(self.foot, data, None);
+    }
+
+    pub fn pop_front(&mut self) -> Option<Pin<&'a T>> {
+        self.head.map(|node| self.unlink(node))
+    }
+
+    pub fn pop_back(&mut self) -> Option<Pin<&'a T>> {
+        self.foot.map(|node| self.unlink(node))
+    }
+
+    pub fn contains(&self, data: &T) -> bool {
+        data.links().list_id.get() == self.id
+    }
+
+    /// Removes the item from the list, returns false if it was not on this list
+    pub fn remove(&mut self, data: Pin<&'a T>) -> bool {
+        if !self.contains(&data) {
+            return false;
+        }
+
+        self.unlink(NonNull::from(data.get_ref()));
+        true
+    }
+
+    pub fn iter(&'_ self) -> Iter<'a, T> {
+        Iter {
+            current_front: self.head,
+            current_back: self.foot,
+            length: self.length,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn cursor_mut(&mut self) -> Cursor<'_, 'a, T> {
+        Cursor {
+            next: self.head,
+            previous: None,
+            list: self,
+        }
+    }
+
+    /// Links `data` in between `previous` and `next`, which must be adjacent on this list
+    fn link(&mut self, previous: ItemLink<T>, data: Pin<&'a T>, next: ItemLink<T>) {
+        let links = data.links();
+        assert!(!links.is_linked(), "item is already linked into a list");
+
+        let node = NonNull::from(data.get_ref());
+        links.previous.set(previous);
+        links.next.set(next);
+        links.list_id.set(self.id);
+
+        match previous {
+            Some(previous) => links_of(previous).next.set(Some(node)),
+            None => self.head = Some(node),
+        }
+
+        match next {
+            Some(next) => links_of(next).previous.set(Some(node)),
+            None => self.foot = Some(node),
+        }
+
+        self.length += 1;
+    }
+
+    /// Unlinks `node`, which must be on this list
+    fn unlink(&mut self, node: NonNull<T>) -> Pin<&'a T> {
+        let links = links_of(node);
+        let previous = links.previous.take();
+        let next = links.next.take();
+        links.list_id.set(0);
+
+        match previous {
+            Some(previous) => links_of(previous).next.set(next),
+            None => self.head = next,
+        }
+
+        match next {
+            Some(next) => links_of(next).previous.set(previous),
+            None => self.foot = previous,
+        }
+
+        self.length -= 1;
+
+        item(node)
+    }
+}
+
+/// Gets the item behind a node of a list borrowing it for `'a`
+fn item<'a, T>(node: NonNull<T>) -> Pin<&'a T> {
+    // The list holds a pinned borrow of every item on it
+    unsafe { Pin::new_unchecked(&*node.as_ptr()) }
+}
+
+fn links_of<'a, T: Linked>(node: NonNull<T>) -> &'a Links<T> {
+    item(node).get_ref().links()
+}
+
+impl<'a, T: Linked> Default for IntrusiveList<'a, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T: Linked> Drop for IntrusiveList<'a, T> {
+    fn drop(&mut self) {
+        // Leave the items unlinked, so they can go on another list
+        while self.pop_back().is_some() {}
+    }
+}
+
+/// A cursor between two items of an `IntrusiveList`, like `linked_list::Cursor`
+pub struct Cursor<'c, 'a, T: Linked> {
+    next: ItemLink<T>,
+    previous: ItemLink<T>,
+    list: &'c mut IntrusiveList<'a, T>,
+}
+
+impl<'c, 'a, T: Linked> Cursor<'c, 'a, T> {
+    pub fn next_item(&self) -> Option<Pin<&'a T>> {
+        self.next.map(item)
+    }
+
+    pub fn previous_item(&self) -> Option<Pin<&'a T>> {
+        self.previous.map(item)
+    }
+
+    pub fn move_next(&mut self) {
+        if let Some(next) = self.next {
+            self.next = links_of(next).next.get();
+            self.previous = Some(next);
+        }
+    }
+
+    pub fn move_previous(&mut self) {
+        if let Some(previous) = self.previous {
+            self.previous = links_of(previous).previous.get();
+            self.next = Some(previous);
+        }
+    }
+
+    /// Inserts the item at the cursor, the cursor ends up right after it.
+    ///
+    /// # Panics
+    /// If the item is already on a list.
+    pub fn insert(&mut self, data: Pin<&'a T>) {
+        self.list.link(self.previous, data, self.next);
+        self.previous = Some(NonNull::from(data.get_ref()));
+    }
+
+    pub fn remove_next(&mut self) -> Option<Pin<&'a T>> {
+        self.next.map(|node| {
+            self.next = links_of(node).next.get();
+            self.list.unlink(node)
+        })
+    }
+
+    pub fn remove_previous(&mut self) -> Option<Pin<&'a T>> {
+        self.previous.map(|node| {
+            self.previous = links_of(node).previous.get();
+            self.list.unlink(node)
+        })
+    }
+}
+
+pub struct Iter<'a, T> {
+    current_front: ItemLink<T>,
+    current_back: ItemLink<T>,
+    length: usize,
+    _phantom: PhantomData<&'a T>,
+}
+
+impl<'a, T: Linked> Iterator for Iter<'a, T> {
+    type Item = Pin<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.length == 0 {
+            return None;
+        }
+
+        self.current_front.map(|node| {
+            self.current_front = links_of(node).next.get();
+            self.length -= 1;
+
+            item(node)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.length, Some(self.length))
+    }
+}
+
+impl<'a, T: Linked> ExactSizeIterator for Iter<'a, T> {
+    fn len(&self) -> usize {
+        self.length
+    }
+}
+
+impl<'a, T: Linked> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.length == 0 {
+            return None;
+        }
+
+        self.current_back.map(|node| {
+            self.current_back = links_of(node).previous.get();
+            self.length -= 1;
+
+            item(node)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::pin::pin;
+
+    struct Task {
+        id: u32,
+        links: Links<Task>,
+    }
+
+    impl Task {
+        fn new(id: u32) -> Self {
+            Self { id, links: Links::new() }
+        }
+    }
+
+    crate::intrusive_adapter!(Task, links);
+
+    fn ids(list: &IntrusiveList<'_, Task>) -> Vec<u32> {
+        list.iter().map(|task| task.id).collect()
+    }
+
+    #[test]
+    fn test_push_pop() {
+        let foo = pin!(Task::new(1337));
+        let bar = pin!(Task::new(42));
+        let baz = Box::pin(Task::new(666));
+
+        let mut list = IntrusiveList::new();
+        assert!(list.is_empty());
+
+        list.push_back(foo.into_ref());
+        list.push_back(baz.as_ref());
+        list.push_front(bar.into_ref());
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(ids(&list), vec![42, 1337, 666]);
+        assert_eq!(list.iter().rev().map(|task| task.id).collect::<Vec<_>>(), vec![666, 1337, 42]);
+        assert_eq!(list.front().unwrap().id, 42);
+        assert_eq!(list.back().unwrap().id, 666);
+
+        assert_eq!(list.pop_back().unwrap().id, 666);
+        assert_eq!(list.pop_front().unwrap().id, 42);
+        assert_eq!(list.pop_front().unwrap().id, 1337);
+        assert!(list.pop_front().is_none());
+        assert!(list.is_empty());
+        assert!(!baz.links.is_linked());
+    }
+
+    #[test]
+    fn test_remove() {
+        let foo = pin!(Task::new(1337));
+        let foo = foo.into_ref();
+        let bar = pin!(Task::new(42));
+        let bar = bar.into_ref();
+        let baz = pin!(Task::new(666));
+        let baz = baz.into_ref();
+
+        let mut list = IntrusiveList::new();
+        let mut other_list = IntrusiveList::new();
+        list.push_back(foo);
+        list.push_back(bar);
+        other_list.push_back(baz);
+
+        assert!(list.contains(&bar));
+        assert!(!list.contains(&baz));
+        assert!(!list.remove(baz));
+
+        assert!(list.remove(bar));
+        assert!(!bar.links.is_linked());
+        assert_eq!(ids(&list), vec![1337]);
+
+        // Free to go on another list now
+        other_list.push_front(bar);
+        assert_eq!(ids(&other_list), vec![42, 666]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_push_linked() {
+        let foo = pin!(Task::new(1337));
+        let foo = foo.into_ref();
+
+        let mut list = IntrusiveList::new();
+        let mut other_list = IntrusiveList::new();
+        list.push_back(foo);
+        other_list.push_back(foo);
+    }
+
+    #[test]
+    fn test_drop_unlinks() {
+        let foo = pin!(Task::new(1337));
+        let foo = foo.into_ref();
+        let bar = pin!(Task::new(42));
+        let bar = bar.into_ref();
+
+        let mut list = IntrusiveList::new();
+        list.push_back(foo);
+        list.push_back(bar);
+        drop(list);
+
+        assert!(!foo.links.is_linked());
+        assert!(!bar.links.is_linked());
+    }
+
+    #[test]
+    fn test_cursor() {
+        let foo = pin!(Task::new(1337));
+        let foo = foo.into_ref();
+        let bar = pin!(Task::new(42));
+        let bar = bar.into_ref();
+        let baz = pin!(Task::new(666));
+        let baz = baz.into_ref();
+
+        let mut list = IntrusiveList::new();
+        list.push_back(foo);
+        list.push_back(baz);
+
+        let mut cursor = list.cursor_mut();
+        assert_eq!(cursor.next_item().unwrap().id, 1337);
+        assert!(cursor.previous_item().is_none());
+        cursor.move_next();
+        cursor.insert(bar);
+        assert_eq!(cursor.previous_item().unwrap().id, 42);
+        assert_eq!(cursor.next_item().unwrap().id, 666);
+
+        cursor.move_previous();
+        cursor.move_previous();
+        assert_eq!(cursor.next_item().unwrap().id, 1337);
+        assert_eq!(cursor.remove_next().unwrap().id, 1337);
+        assert_eq!(cursor.next_item().unwrap().id, 42);
+
+        cursor.move_next();
+        assert_eq!(cursor.remove_previous().unwrap().id, 42);
+        assert!(cursor.previous_item().is_none());
+        assert_eq!(cursor.next_item().unwrap().id, 666);
+
+        assert_eq!(list.len(), 1);
+        assert_eq!(ids(&list), vec![666]);
+    }
+}
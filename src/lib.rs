@@ -1,5 +1,26 @@
 pub mod node;
+pub mod error;
+pub mod heap_size;
 pub mod linked_list;
+pub mod list_slice;
+pub mod circular_list;
+pub mod forward_list;
+pub mod persistent_list;
+pub mod intrusive_list;
+pub mod chunked_list;
+pub mod indexed_list;
+pub mod lru_cache;
+pub mod linked_hash_map;
+pub mod linked_hash_set;
+pub mod blocking_deque;
+pub mod mpsc_queue;
+
+pub use error::Error;
+
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "rayon")]
+pub mod rayon;
 
 /// Get data from node
 #[doc(hidden)]
@@ -76,7 +97,7 @@ macro_rules! previous_unsafe {
 #[macro_export]
 macro_rules! linkedlist {
     () => (
-        linked_list::new()
+        $crate::linked_list::LinkedList::new()
     );
     // match val, val, ...
     ($($val:expr$(,)?)+) => (
@@ -88,3 +109,38 @@ macro_rules! linkedlist {
         }
     );
 }
+
+/// Creates a ForwardList containing the arguments.
+///
+/// The first argument ends up at the front:
+/// ```
+/// # #[macro_use] extern crate doubly_linked_list;
+/// let list = forwardlist!("foo", "bar", "baz");
+///
+/// let mut list_iter = list.iter();
+/// assert_eq!(list_iter.next(), Some(&"foo"));
+/// assert_eq!(list_iter.next(), Some(&"bar"));
+/// assert_eq!(list_iter.next(), Some(&"baz"));
+/// assert_eq!(list_iter.next(), None);
+///
+/// assert_eq!(list.peek(), Some(&"foo"));
+/// ```
+#[macro_export]
+macro_rules! forwardlist {
+    () => (
+        $crate::forward_list::ForwardList::new()
+    );
+    // match val, val, ...
+    ($($val:expr$(,)?)+) => (
+        {
+            let mut list = $crate::forward_list::ForwardList::new();
+            {
+                // Append through a cursor, so the order is kept
+                let mut cursor = list.cursor_mut();
+                $(cursor.insert_after($val); cursor.move_next();)*
+            }
+
+            list
+        }
+    );
+}
@@ -0,0 +1,503 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::iter::FromIterator;
+use std::fmt;
+
+use crate::linked_list::{self, LinkedList};
+use crate::node::NodeLinkSome;
+
+/// A hash map which remembers the order its keys were inserted in.
+///
+/// The entries are kept in a `LinkedList` in insertion order, and a `HashMap` finds
+/// the node of a key, so lookups, insertion and removal are all O(1). Inserting a key
+/// that is already in the map replaces its value, but keeps its place.
+pub struct LinkedHashMap<K, V> {
+    list: LinkedList<(K, V)>,
+    map: HashMap<K, NodeLinkSome<(K, V)>>,
+}
+
+impl<K: Hash + Eq + Clone, V> LinkedHashMap<K, V> {
+    pub fn new() -> Self {
+        Self {
+            list: LinkedList::new(),
+            map: HashMap::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(key)
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).map(|node| unsafe { &(*node.as_ptr()).data.1 })
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).map(|node| unsafe { &mut (*node.as_ptr()).data.1 })
+    }
+
+    /// Inserts `value` at the back, or replaces the value of `key` in its place if it is there
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.entry(key) {
+            Entry::Occupied(mut entry) => Some(entry.insert(value)),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+                None
+            }
+        }
+    }
+
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.remove(key).map(|node| unsafe { self.remove_node(node) })
+    }
+
+    /// Gets the entry of `key` for in place manipulation
+    pub fn entry(&'_ mut self, key: K) -> Entry<'_, K, V> {
+        match self.map.get(&key) {
+            Some(&node) => Entry::Occupied(OccupiedEntry { node, map: self }),
+            None => Entry::Vacant(VacantEntry { key, map: self }),
+        }
+    }
+
+    /// Gets the entry inserted first
+    pub fn front(&self) -> Option<(&K, &V)> {
+        self.iter().next()
+    }
+
+    /// Gets the entry inserted last
+    pub fn back(&self) -> Option<(&K, &V)> {
+        self.iter().next_back()
+    }
+
+    pub fn pop_front(&mut self) -> Option<(K, V)> {
+        let (key, value) = self.list.pop_front()?;
+        self.map.remove(&key);
+
+        Some((key, value))
+    }
+
+    pub fn pop_back(&mut self) -> Option<(K, V)> {
+        let (key, value) = self.list.pop_back()?;
+        self.map.remove(&key);
+
+        Some((key, value))
+    }
+
+    /// Moves the entry of `key` to the back, as if it was inserted last.
+    ///
+    /// Returns false if `key` is not in the map.
+    pub fn move_to_back<Q>(&mut self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let Some(&node) = self.map.get(key) else {
+            return false;
+        };
+
+        if self.list.foot_link() != Some(node) {
+            unsafe { self.list.unlink_node(node) };
+            self.list.link_node(self.list.foot_link(), node, None);
+        }
+
+        true
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.list = LinkedList::new();
+    }
+
+    /// Iterates over the entries in insertion order
+    pub fn iter(&'_ self) -> Iter<'_, K, V> {
+        Iter { iter: self.list.iter() }
+    }
+
+    /// Iterates over the entries in insertion order, with mutable values
+    pub fn iter_mut(&'_ mut self) -> IterMut<'_, K, V> {
+        IterMut { iter: self.list.iter_mut() }
+    }
+
+    pub fn keys(&'_ self) -> impl DoubleEndedIterator<Item = &'_ K> + ExactSizeIterator {
+        self.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&'_ self) -> impl DoubleEndedIterator<Item = &'_ V> + ExactSizeIterator {
+        self.iter().map(|(_, value)| value)
+    }
+
+    pub fn values_mut(&'_ mut self) -> impl DoubleEndedIterator<Item = &'_ mut V> + ExactSizeIterator {
+        self.iter_mut().map(|(_, value)| value)
+    }
+
+    /// Unlinks and frees `node`, which must already be gone from the map
+    unsafe fn remove_node(&mut self, node: NodeLinkSome<(K, V)>) -> (K, V) {
+        self.list.unlink_node(node);
+        Box::from_raw(node.as_ptr()).data
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> Default for LinkedHashMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Clone for LinkedHashMap<K, V> {
+    fn clone(&self) -> Self {
+        self.iter().map(|(key, value)| (key.clone(), value.clone())).collect()
+    }
+}
+
+/// Maps are equal if they have the same entries in the same order
+impl<K: Hash + Eq + Clone, V: PartialEq> PartialEq for LinkedHashMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for LinkedHashMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.list.iter().map(|(key, value)| (key, value))).finish()
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> FromIterator<(K, V)> for LinkedHashMap<K, V> {
+    fn from_iter<U: IntoIterator<Item = (K, V)>>(iter: U) -> Self {
+        let mut map = LinkedHashMap::new();
+        map.extend(iter);
+
+        map
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> Extend<(K, V)> for LinkedHashMap<K, V> {
+    fn extend<U: IntoIterator<Item = (K, V)>>(&mut self, iter: U) {
+        iter.into_iter().for_each(|(key, value)| { self.insert(key, value); });
+    }
+}
+
+/// An entry of a `LinkedHashMap`, which is either there or not
+pub enum Entry<'a, K, V> {
+    Occupied(OccupiedEntry<'a, K, V>),
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+impl<'a, K: Hash + Eq + Clone, V> Entry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+
+        self
+    }
+}
+
+pub struct OccupiedEntry<'a, K, V> {
+    node: NodeLinkSome<(K, V)>,
+    map: &'a mut LinkedHashMap<K, V>,
+}
+
+impl<'a, K: Hash + Eq + Clone, V> OccupiedEntry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        unsafe { &(*self.node.as_ptr()).data.0 }
+    }
+
+    pub fn get(&self) -> &V {
+        unsafe { &(*self.node.as_ptr()).data.1 }
+    }
+
+    pub fn get_mut(&mut self) -> &mut V {
+        unsafe { &mut (*self.node.as_ptr()).data.1 }
+    }
+
+    pub fn into_mut(self) -> &'a mut V {
+        unsafe { &mut (*self.node.as_ptr()).data.1 }
+    }
+
+    /// Replaces the value, keeping the entry in its place
+    pub fn insert(&mut self, value: V) -> V {
+        std::mem::replace(self.get_mut(), value)
+    }
+
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    pub fn remove_entry(self) -> (K, V) {
+        let key = unsafe { &(*self.node.as_ptr()).data.0 };
+        self.map.map.remove(key);
+        unsafe { self.map.remove_node(self.node) }
+    }
+}
+
+pub struct VacantEntry<'a, K, V> {
+    key: K,
+    map: &'a mut LinkedHashMap<K, V>,
+}
+
+impl<'a, K: Hash + Eq + Clone, V> VacantEntry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Inserts `value` at the back of the map
+    pub fn insert(self, value: V) -> &'a mut V {
+        let node = LinkedList::new_node_link((self.key.clone(), value));
+        self.map.list.link_node(self.map.list.foot_link(), node, None);
+        self.map.map.insert(self.key, node);
+
+        unsafe { &mut (*node.as_ptr()).data.1 }
+    }
+}
+
+pub struct Iter<'a, K, V> {
+    iter: linked_list::Iter<'a, (K, V)>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, value)| (key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Iter<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(key, value)| (key, value))
+    }
+}
+
+pub struct IterMut<'a, K, V> {
+    iter: linked_list::IterMut<'a, (K, V)>,
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, value)| (&*key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for IterMut<'a, K, V> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for IterMut<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(key, value)| (&*key, value))
+    }
+}
+
+pub struct IntoIter<K, V> {
+    iter: linked_list::IntoIter<(K, V)>,
+}
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for IntoIter<K, V> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for IntoIter<K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<K, V> IntoIterator for LinkedHashMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        // The map only points into the list, so the list can go on its own
+        IntoIter { iter: self.list.into_iter() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LinkedHashMap, Entry};
+
+    #[test]
+    fn test_empty() {
+        let mut linked_hash_map = LinkedHashMap::<u32, u32>::new();
+        assert!(linked_hash_map.is_empty());
+        assert_eq!(linked_hash_map.get(&1337), None);
+        assert_eq!(linked_hash_map.front(), None);
+        assert_eq!(linked_hash_map.pop_back(), None);
+        assert!(!linked_hash_map.move_to_back(&1337));
+    }
+
+    #[test]
+    fn test_insert_remove() {
+        let mut linked_hash_map = LinkedHashMap::new();
+        assert_eq!(linked_hash_map.insert("foo", 1337), None);
+        assert_eq!(linked_hash_map.insert("bar", 42), None);
+        assert_eq!(linked_hash_map.insert("baz", 666), None);
+
+        // Replacing keeps the place
+        assert_eq!(linked_hash_map.insert("foo", 7), Some(1337));
+        assert_eq!(linked_hash_map.keys().collect::<Vec<_>>(), vec![&"foo", &"bar", &"baz"]);
+
+        assert_eq!(linked_hash_map.remove("bar"), Some(42));
+        assert_eq!(linked_hash_map.remove("bar"), None);
+        assert!(!linked_hash_map.contains_key("bar"));
+
+        *linked_hash_map.get_mut("baz").unwrap() += 1;
+        assert_eq!(linked_hash_map.get("baz"), Some(&667));
+        assert_eq!(linked_hash_map.len(), 2);
+        assert_eq!(linked_hash_map.iter().collect::<Vec<_>>(), vec![(&"foo", &7), (&"baz", &667)]);
+    }
+
+    #[test]
+    fn test_front_back() {
+        let mut linked_hash_map = LinkedHashMap::from_iter(vec![(1337, "foo"), (42, "bar"), (666, "baz")]);
+        assert_eq!(linked_hash_map.front(), Some((&1337, &"foo")));
+        assert_eq!(linked_hash_map.back(), Some((&666, &"baz")));
+
+        assert!(linked_hash_map.move_to_back(&1337));
+        assert_eq!(linked_hash_map.back(), Some((&1337, &"foo")));
+
+        assert_eq!(linked_hash_map.pop_front(), Some((42, "bar")));
+        assert_eq!(linked_hash_map.pop_back(), Some((1337, "foo")));
+        assert!(!linked_hash_map.contains_key(&1337));
+        assert_eq!(linked_hash_map.into_iter().collect::<Vec<_>>(), vec![(666, "baz")]);
+    }
+
+    #[test]
+    fn test_entry() {
+        let mut linked_hash_map = LinkedHashMap::new();
+
+        for word in "foo bar foo baz foo bar".split(' ') {
+            *linked_hash_map.entry(word).or_insert(0) += 1;
+        }
+        assert_eq!(linked_hash_map.iter().collect::<Vec<_>>(), vec![(&"foo", &3), (&"bar", &2), (&"baz", &1)]);
+
+        linked_hash_map.entry("bar").and_modify(|count| *count *= 10).or_default();
+        linked_hash_map.entry("qux").and_modify(|count| *count *= 10).or_default();
+        assert_eq!(linked_hash_map.values().collect::<Vec<_>>(), vec![&3, &20, &1, &0]);
+
+        match linked_hash_map.entry("foo") {
+            Entry::Occupied(entry) => {
+                assert_eq!(entry.key(), &"foo");
+                assert_eq!(entry.remove_entry(), ("foo", 3));
+            }
+            Entry::Vacant(_) => unreachable!(),
+        }
+
+        assert_eq!(linked_hash_map.len(), 3);
+        assert_eq!(linked_hash_map.front(), Some((&"bar", &20)));
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut linked_hash_map = LinkedHashMap::from_iter(vec![(1337, 1), (42, 2), (666, 3)]);
+
+        linked_hash_map.values_mut().for_each(|value| *value *= 2);
+        linked_hash_map.iter_mut().rev().take(1).for_each(|(_, value)| *value = 0);
+
+        let mut linked_hash_map_iter = linked_hash_map.iter();
+        assert_eq!(linked_hash_map_iter.len(), 3);
+        assert_eq!(linked_hash_map_iter.next(), Some((&1337, &2)));
+        assert_eq!(linked_hash_map_iter.next_back(), Some((&666, &0)));
+        assert_eq!(linked_hash_map_iter.next(), Some((&42, &4)));
+        assert_eq!(linked_hash_map_iter.next(), None);
+
+        let cloned_linked_hash_map = linked_hash_map.clone();
+        assert_eq!(cloned_linked_hash_map, linked_hash_map);
+        assert_eq!(format!("{:?}", cloned_linked_hash_map), "{1337: 2, 42: 4, 666: 0}");
+
+        linked_hash_map.clear();
+        assert!(linked_hash_map.is_empty());
+        assert!(!linked_hash_map.contains_key(&42));
+    }
+}
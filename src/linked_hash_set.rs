@@ -0,0 +1,226 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+use std::iter::FromIterator;
+use std::fmt;
+
+use crate::linked_hash_map::{self, LinkedHashMap};
+
+/// A hash set which remembers the order its values were inserted in.
+///
+/// A thin wrapper around a `LinkedHashMap` without values.
+pub struct LinkedHashSet<T> {
+    map: LinkedHashMap<T, ()>,
+}
+
+impl<T: Hash + Eq + Clone> LinkedHashSet<T> {
+    pub fn new() -> Self {
+        Self { map: LinkedHashMap::new() }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(value)
+    }
+
+    /// Inserts `value` at the back, returning false if it was already there.
+    ///
+    /// A value that is already there keeps its place.
+    pub fn insert(&mut self, value: T) -> bool {
+        self.map.insert(value, ()).is_none()
+    }
+
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.remove(value).is_some()
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.map.front().map(|(value, _)| value)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.map.back().map(|(value, _)| value)
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.map.pop_front().map(|(value, _)| value)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.map.pop_back().map(|(value, _)| value)
+    }
+
+    /// Moves `value` to the back, returning false if it is not in the set
+    pub fn move_to_back<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.move_to_back(value)
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Iterates over the values in insertion order
+    pub fn iter(&'_ self) -> Iter<'_, T> {
+        Iter { iter: self.map.iter() }
+    }
+}
+
+impl<T: Hash + Eq + Clone> Default for LinkedHashSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Hash + Eq + Clone> Clone for LinkedHashSet<T> {
+    fn clone(&self) -> Self {
+        Self { map: self.map.clone() }
+    }
+}
+
+/// Sets are equal if they have the same values in the same order
+impl<T: Hash + Eq + Clone> PartialEq for LinkedHashSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<T: Hash + Eq + Clone + fmt::Debug> fmt::Debug for LinkedHashSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.map.iter().map(|(value, _)| value)).finish()
+    }
+}
+
+impl<T: Hash + Eq + Clone> FromIterator<T> for LinkedHashSet<T> {
+    fn from_iter<U: IntoIterator<Item = T>>(iter: U) -> Self {
+        let mut set = LinkedHashSet::new();
+        set.extend(iter);
+
+        set
+    }
+}
+
+impl<T: Hash + Eq + Clone> Extend<T> for LinkedHashSet<T> {
+    fn extend<U: IntoIterator<Item = T>>(&mut self, iter: U) {
+        self.map.extend(iter.into_iter().map(|value| (value, ())));
+    }
+}
+
+pub struct Iter<'a, T> {
+    iter: linked_hash_map::Iter<'a, T, ()>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(value, _)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(value, _)| value)
+    }
+}
+
+pub struct IntoIter<T> {
+    iter: linked_hash_map::IntoIter<T, ()>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(value, _)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(value, _)| value)
+    }
+}
+
+impl<T> IntoIterator for LinkedHashSet<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { iter: self.map.into_iter() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinkedHashSet;
+
+    #[test]
+    fn test_insert_remove() {
+        let mut linked_hash_set = LinkedHashSet::new();
+        assert!(linked_hash_set.insert("foo"));
+        assert!(linked_hash_set.insert("bar"));
+        assert!(linked_hash_set.insert("baz"));
+        assert!(!linked_hash_set.insert("foo"));
+
+        assert_eq!(linked_hash_set.len(), 3);
+        assert_eq!(linked_hash_set.iter().collect::<Vec<_>>(), vec![&"foo", &"bar", &"baz"]);
+
+        assert!(linked_hash_set.remove("bar"));
+        assert!(!linked_hash_set.remove("bar"));
+        assert!(linked_hash_set.contains("baz"));
+        assert_eq!(format!("{:?}", linked_hash_set), r#"{"foo", "baz"}"#);
+    }
+
+    #[test]
+    fn test_order() {
+        let mut linked_hash_set = LinkedHashSet::from_iter(vec![1337, 42, 666, 42]);
+        assert_eq!(linked_hash_set.front(), Some(&1337));
+        assert_eq!(linked_hash_set.back(), Some(&666));
+
+        assert!(linked_hash_set.move_to_back(&1337));
+        assert_eq!(linked_hash_set.iter().rev().collect::<Vec<_>>(), vec![&1337, &666, &42]);
+
+        assert_eq!(linked_hash_set.pop_front(), Some(42));
+        assert_eq!(linked_hash_set.pop_back(), Some(1337));
+        assert_eq!(linked_hash_set.clone(), LinkedHashSet::from_iter(vec![666]));
+        assert_eq!(linked_hash_set.into_iter().collect::<Vec<_>>(), vec![666]);
+    }
+}
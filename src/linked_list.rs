@@ -1,10 +1,18 @@
 use std::ptr::NonNull;
 use std::marker::PhantomData;
+use std::mem::{self, ManuallyDrop};
 use std::iter::{zip, FromIterator};
 use std::fmt;
-
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::{Bound, Index, IndexMut, RangeBounds};
+
+use crate::error::Error;
+use crate::heap_size::ListStats;
+use crate::list_slice::{ListSlice, ListSliceMut};
 use crate::node::{Node, NodeLink, NodeLinkSome};
-use crate::{next_unsafe, next, previous, data_unsafe, previous_unsafe};
+use crate::{next_unsafe, next, previous, data, data_unsafe, previous_unsafe};
 
 #[derive(Debug)]
 pub struct LinkedList<T> {
@@ -26,7 +34,31 @@ impl<T> LinkedList<T> {
         self.length
     }
 
-    fn new_node_link(data: T) -> NodeLinkSome<T> {
+    /// The size of one node, its element and the two links
+    pub const fn node_size() -> usize {
+        mem::size_of::<Node<T>>()
+    }
+
+    /// The bytes the nodes take up on the heap, not counting what the elements own themselves
+    pub fn allocated_bytes(&self) -> usize {
+        self.length * Self::node_size()
+    }
+
+    /// Reports the number of nodes and how much of their memory goes to the links
+    pub fn stats(&self) -> ListStats {
+        let bytes_per_node = Self::node_size();
+        let element_bytes = mem::size_of::<T>();
+
+        ListStats {
+            nodes: self.length,
+            bytes_per_node,
+            element_bytes,
+            allocated_bytes: self.allocated_bytes(),
+            overhead_ratio: (bytes_per_node - element_bytes) as f64 / bytes_per_node as f64,
+        }
+    }
+
+    pub(crate) fn new_node_link(data: T) -> NodeLinkSome<T> {
         let new_node = Box::new(Node::<T>::new(data));
         unsafe {
             NonNull::new_unchecked(Box::into_raw(new_node))
@@ -34,47 +66,157 @@ impl<T> LinkedList<T> {
     }
 
     pub fn push_front(&mut self, data: T) {
-        Cursor {next: self.head, previous: None, list: self}
+        Cursor {next: self.head, previous: None, index: 0, ghost: false, list: self}
             .push(data);
     }
 
     pub fn push_back(&mut self, data: T) {
-        Cursor {next: None, previous: self.foot, list: self}
+        Cursor {next: None, previous: self.foot, index: self.length, ghost: false, list: self}
             .push(data);
     }
 
     pub fn get(&self, index: usize) -> Option<&T>
     {
         self.iter()
-            .skip(index)
-            .next()
+            .nth(index)
     }
 
-    pub fn insert(&mut self, index: usize, data: T) {
-        // Create cursor at front of the list
-        let mut cursor = Cursor {
-            next: self.head,
-            previous: None,
-            list: self
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.node_at(index).map(|node| unsafe { &mut (*node.as_ptr()).data })
+    }
+
+    /// Gets the element at `index`, seeking it from the nearer end
+    pub fn try_get(&self, index: usize) -> Result<&T, Error> {
+        self.node_at(index)
+            .map(|node| data_unsafe!(node))
+            .ok_or(Error::IndexOutOfBounds { index, len: self.length })
+    }
+
+    /// Inserts `data` so it ends up at `index`, shifting everything from there back.
+    ///
+    /// Unlike `insert`, `index` may be anything from 0 up to and including the length.
+    pub fn try_insert(&mut self, index: usize, data: T) -> Result<(), Error> {
+        if index > self.length {
+            return Err(Error::IndexOutOfBounds { index, len: self.length });
+        }
+
+        // In front of the node at index, or at the back if index is the length
+        let next = self.node_at(index);
+        let previous = match next {
+            Some(next) => previous_unsafe!(next),
+            None => self.foot,
         };
 
-        // Move the cursor next `index + 1` times
-        (0 .. index + 1).for_each(|_| cursor.move_next());
+        self.link_node(previous, Self::new_node_link(data), next);
+
+        Ok(())
+    }
+
+    /// Removes the element at `index`, seeking it from the nearer end
+    pub fn try_remove(&mut self, index: usize) -> Result<T, Error> {
+        let node = self.node_at(index)
+            .ok_or(Error::IndexOutOfBounds { index, len: self.length })?;
+
+        unsafe {
+            self.unlink_node(node);
+            Ok(Box::from_raw(node.as_ptr()).data)
+        }
+    }
+
+    /// Removes the element at `index`, seeking it from the nearer end
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        self.try_remove(index).ok()
+    }
+
+    pub fn contains(&self, data: &T) -> bool
+    where
+        T: PartialEq
+    {
+        self.iter().any(|x| x == data)
+    }
+
+    /// Gets the index of the first element matching `predicate`
+    pub fn position<P: FnMut(&T) -> bool>(&self, predicate: P) -> Option<usize> {
+        self.iter().position(predicate)
+    }
+
+    /// Gets the index of the last element matching `predicate`, searching from the back
+    pub fn rposition<P: FnMut(&T) -> bool>(&self, predicate: P) -> Option<usize> {
+        self.iter().rposition(predicate)
+    }
+
+    /// Gets the first element matching `predicate` mutably
+    pub fn find_mut<P: FnMut(&T) -> bool>(&mut self, mut predicate: P) -> Option<&mut T> {
+        self.iter_mut().find(|x| predicate(x))
+    }
+
+    /// Finds the first element matching `predicate`, and gets a cursor right in front of it.
+    ///
+    /// The match is the cursor's next element, ready to be edited or removed.
+    pub fn find<P: FnMut(&T) -> bool>(&mut self, mut predicate: P) -> Option<Cursor<'_, T>> {
+        let mut current = self.head;
+        let mut index = 0;
+        while let Some(node) = current {
+            if predicate(data_unsafe!(node)) {
+                return Some(Cursor { next: Some(node), previous: previous_unsafe!(node), index, ghost: false, list: self });
+            }
+
+            current = next_unsafe!(node);
+            index += 1;
+        }
+
+        None
+    }
+
+    /// Finds the last element matching `predicate`, and gets a cursor right in front of it.
+    ///
+    /// The match is the cursor's next element, ready to be edited or removed.
+    pub fn rfind<P: FnMut(&T) -> bool>(&mut self, mut predicate: P) -> Option<Cursor<'_, T>> {
+        let mut current = self.foot;
+        let mut index = self.length;
+        while let Some(node) = current {
+            index -= 1;
+            if predicate(data_unsafe!(node)) {
+                return Some(Cursor { next: Some(node), previous: previous_unsafe!(node), index, ghost: false, list: self });
+            }
+
+            current = previous_unsafe!(node);
+        }
+
+        None
+    }
+
+    /// Inserts `data` after the element at `index`, or at the back if there is no such element
+    pub fn insert(&mut self, index: usize, data: T) {
+        let length = self.length;
+        let mut cursor = self.cursor_mut();
+
+        // Move the cursor past the element at `index`, stopping at the back rather than wrapping
+        cursor.seek_to(index.saturating_add(1).min(length));
         cursor.push(data);
     }
 
     pub fn pop_front(&mut self) -> Option<T> {
-        Cursor {next: self.head, previous: None, list: self}
+        Cursor {next: self.head, previous: None, index: 0, ghost: false, list: self}
             .pop_front()
     }
 
     pub fn pop_back(&mut self) -> Option<T> {
-        Cursor {next: None, previous: self.foot, list: self}
+        Cursor {next: None, previous: self.foot, index: self.length, ghost: false, list: self}
             .pop_back()
     }
 
     pub fn iter(&'_ self) -> Iter<'_, T> {
         Iter {
+            current_front: self.head,
+            current_back: self.foot,
+            remaining: self.length,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn iter_mut(&'_ mut self) -> IterMut<'_, T> {
+        IterMut {
             current_front: self.head,
             current_back: self.foot,
             length: self.length,
@@ -82,573 +224,2833 @@ impl<T> LinkedList<T> {
         }
     }
 
+    /// Iterates the elements in `range`, seeking its ends from the nearer end of the list.
+    ///
+    /// # Panics
+    /// If the range is out of bounds, or its start is after its end.
+    pub fn range<R: RangeBounds<usize>>(&self, range: R) -> Range<'_, T> {
+        let (front, back, length) = self.range_nodes(range);
+
+        Range {
+            current_front: front,
+            current_back: back,
+            length,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Mutably iterates the elements in `range`.
+    ///
+    /// # Panics
+    /// If the range is out of bounds, or its start is after its end.
+    pub fn range_mut<R: RangeBounds<usize>>(&mut self, range: R) -> IterMut<'_, T> {
+        let (front, back, length) = self.range_nodes(range);
+
+        IterMut {
+            current_front: front,
+            current_back: back,
+            length,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Removes the elements in `range` and yields them.
+    ///
+    /// The range is unlinked up front, so the list is whole again even if the
+    /// `Drain` is dropped early, and the remaining elements are dropped with it.
+    ///
+    /// # Panics
+    /// If the range is out of bounds, or its start is after its end.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        let (front, back, length) = self.range_nodes(range);
+
+        let list = match (front, back) {
+            (Some(front), Some(back)) => unsafe { self.detach(front, back, length) },
+            _ => LinkedList::new(),
+        };
+
+        Drain { list, _phantom: PhantomData }
+    }
+
+    /// Gets a view of the elements in `range`, seeking its ends from the nearer end of the list.
+    ///
+    /// # Panics
+    /// If the range is out of bounds, or its start is after its end.
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> ListSlice<'_, T> {
+        let (front, back, length) = self.range_nodes(range);
+
+        unsafe { ListSlice::from_nodes(front, back, length) }
+    }
+
+    /// Gets a mutable view of the elements in `range`.
+    ///
+    /// # Panics
+    /// If the range is out of bounds, or its start is after its end.
+    pub fn slice_mut<R: RangeBounds<usize>>(&mut self, range: R) -> ListSliceMut<'_, T> {
+        let (front, back, length) = self.range_nodes(range);
+
+        unsafe { ListSliceMut::from_nodes(self, front, back, length) }
+    }
+
+    /// Gets a cursor at the front of the list
     pub fn cursor_mut(&mut self) -> Cursor<'_, T> {
         Cursor {
             next: self.head,
             previous: None,
+            index: 0,
+            ghost: false,
             list: self
         }
     }
-}
 
-impl<T> Default for LinkedList<T> {
-    fn default() -> Self {
-        Self::new()
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp)
     }
-}
 
-impl<T: PartialEq> PartialEq for LinkedList<T> {
-    fn eq(&self, other: &Self) -> bool {
-        zip(self.iter(), other.iter()).all(|(x,y)| {
-            x == y
-        })
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        self.sort_by(|a, b| f(a).cmp(&f(b)))
     }
-}
 
-impl<T> From<Vec<T>> for LinkedList<T> {
-    fn from(vec: Vec<T>) -> Self {
-        vec.into_iter().collect::<LinkedList<T>>()
+    /// The merge sort is already in place, so this is the same as `sort_by`
+    pub fn sort_unstable_by<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: F) {
+        self.sort_by(compare)
     }
-}
 
-impl<T> FromIterator<T> for LinkedList<T> {
-    fn from_iter<U: IntoIterator<Item = T>>(iter: U) -> Self {
-        let mut list = LinkedList::<T>::new();
-        iter.into_iter().for_each(|x| list.push_back(x));
+    /// Stable bottom-up merge sort, only relinking the nodes.
+    ///
+    /// If `compare` panics the list is left valid, in some order, with all its elements.
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        if self.length < 2 {
+            return;
+        }
 
-        list
+        // The `next` chain from head always holds every node, so we only
+        // need to restore `previous` and `foot` when done, or if `compare` panics
+        let guard = RelinkGuard { list: self };
+        let length = guard.list.length;
+
+        let mut width = 1;
+        while width < length {
+            // Node before the runs being merged, None if they start at head
+            let mut before: NodeLink<T> = None;
+            let mut start = guard.list.head;
+
+            while let Some(a_start) = start {
+                // Find the last node of the left run
+                let mut a_tail = a_start;
+                let mut a_len = 1;
+                while a_len < width {
+                    match next_unsafe!(a_tail) {
+                        Some(node) => a_tail = node,
+                        None => break,
+                    }
+                    a_len += 1;
+                }
+
+                // No right run, the rest is already sorted
+                let Some(b_start) = next_unsafe!(a_tail) else {
+                    break;
+                };
+
+                // Find the last node of the right run
+                let mut b_tail = b_start;
+                let mut b_len = 1;
+                while b_len < width {
+                    match next_unsafe!(b_tail) {
+                        Some(node) => b_tail = node,
+                        None => break,
+                    }
+                    b_len += 1;
+                }
+
+                let mut a = a_start;
+                let mut b = b_start;
+                while a_len > 0 && b_len > 0 {
+                    // Only take from the right run if strictly less, to keep it stable
+                    let take_b = unsafe { compare(data!(b), data!(a)) } == Ordering::Less;
+
+                    if take_b {
+                        unsafe {
+                            // Unlink b from the front of the right run
+                            let b_next = next!(b);
+                            next!(a_tail) = b_next;
+
+                            // And link it in before a
+                            next!(b) = Some(a);
+                            guard.list.link_after(before, b);
+
+                            before = Some(b);
+                            b_len -= 1;
+
+                            if let Some(b_next) = b_next {
+                                b = b_next;
+                            }
+                        }
+                    }
+                    else {
+                        before = Some(a);
+                        a_len -= 1;
+
+                        if let Some(a_next) = next_unsafe!(a) {
+                            a = a_next;
+                        }
+                    }
+                }
+
+                // Whatever is left of the right run is already in place behind the left run
+                let run_end = if b_len > 0 { b_tail } else { a_tail };
+
+                before = Some(run_end);
+                start = next_unsafe!(run_end);
+            }
+
+            width *= 2;
+        }
     }
-}
 
-impl<T: fmt::Display> fmt::Display for LinkedList<T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let format_str = self.iter()
-            .map(|x| format!("{}, ", x))
-            .collect::<String>();
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.is_sorted_by(|a, b| a <= b)
+    }
 
-        write!(f, "{}", format_str)
+    pub fn is_sorted_by<F: FnMut(&T, &T) -> bool>(&self, mut compare: F) -> bool {
+        zip(self.iter(), self.iter().skip(1)).all(|(a, b)| compare(a, b))
     }
-}
 
-impl<T> Drop for LinkedList<T> {
-    fn drop(&mut self) {
-        while self.pop_back().is_some() {}
+    /// Moves all nodes of `other` to the back of this list in O(1)
+    pub fn append(&mut self, other: &mut Self) {
+        let Some(other_head) = other.head.take() else {
+            return;
+        };
+
+        match self.foot {
+            Some(foot) => unsafe {
+                next!(foot) = Some(other_head);
+                previous!(other_head) = Some(foot);
+            },
+            None => self.head = Some(other_head),
+        }
+
+        self.foot = other.foot.take();
+        self.length += other.length;
+        other.length = 0;
     }
-}
 
-pub struct Cursor<'a, T> {
-    next: NodeLink<T>,
-    previous: NodeLink<T>,
-    list: &'a mut LinkedList<T>,
-}
+    pub fn merge(&mut self, other: &mut Self)
+    where
+        T: Ord,
+    {
+        self.merge_by(other, T::cmp)
+    }
 
-impl<'a, T> Cursor<'a, T> {
-    pub fn next_data(&self) -> Option<&'a T> {
-        self.next.map(|node| data_unsafe!(node))
+    /// Splices the sorted `other` into this sorted list, leaving `other` empty.
+    ///
+    /// Equal elements from `self` are kept before those from `other`.
+    pub fn merge_by<F: FnMut(&T, &T) -> Ordering>(&mut self, other: &mut Self, mut compare: F) {
+        let mut current = self.head;
+
+        while let (Some(a), Some(b)) = (current, other.head) {
+            if unsafe { compare(data!(b), data!(a)) } == Ordering::Less {
+                // Move b over in front of a, one node at a time so both lists
+                // stay valid should `compare` panic
+                unsafe {
+                    other.unlink_node(b);
+                    self.link_node(previous!(a), b, Some(a));
+                }
+            }
+            else {
+                current = next_unsafe!(a);
+            }
+        }
+
+        // Everything left in other belongs after our foot
+        self.append(other);
     }
 
-    pub fn previous_data(&self) -> Option<&'a T> {
-        self.previous.map(|node| data_unsafe!(node))
+    /// Joins `lists` end to end in O(k) for k lists, without touching their elements
+    pub fn concat<I: IntoIterator<Item = LinkedList<T>>>(lists: I) -> Self {
+        lists.into_iter().fold(LinkedList::new(), |mut list, mut other| {
+            list.append(&mut other);
+            list
+        })
     }
 
-    pub fn move_next(&mut self) {
-        if let Some(next) = self.next {
-            // Set next to nexts next
-            self.next = next_unsafe!(next);
+    /// Splits the list into the elements that match `predicate` and those that don't,
+    /// by moving the nodes over, so nothing is allocated and the order is kept
+    pub fn partition_in_place<P: FnMut(&T) -> bool>(mut self, mut predicate: P) -> (Self, Self) {
+        let mut matching = LinkedList::new();
+        let mut rest = LinkedList::new();
 
-            // Set previous to next
-            self.previous = Some(next);
+        // One node at a time, so every node is in some list should `predicate` panic
+        while let Some(node) = self.head {
+            let list = if predicate(data_unsafe!(node)) { &mut matching } else { &mut rest };
+
+            unsafe { self.unlink_node(node) };
+            list.link_node(list.foot, node, None);
         }
+
+        (matching, rest)
     }
 
-    pub fn move_previous(&mut self) {
-        if let Some(previous) = self.previous {
-            // Set previous to previous' previous
-            self.previous = previous_unsafe!(previous);
+    /// Splits the list in between every two neighbours for which `predicate` holds,
+    /// handing out the pieces as lists of the original nodes
+    pub fn split_when<P: FnMut(&T, &T) -> bool>(self, predicate: P) -> SplitWhen<T, P> {
+        SplitWhen { list: self, predicate }
+    }
 
-            // Set next to previous
-            self.next = Some(previous);
+    /// Groups the elements by `key`, moving the nodes over into one list per key.
+    ///
+    /// The groups come in the order their keys first show up, each keeping the order of its elements.
+    pub fn group_by_key<K: Hash + Eq, F: FnMut(&T) -> K>(mut self, mut key: F) -> Vec<(K, LinkedList<T>)> {
+        let mut indices = HashMap::new();
+        let mut groups: Vec<LinkedList<T>> = Vec::new();
+
+        while let Some(node) = self.head {
+            let index = *indices.entry(key(data_unsafe!(node))).or_insert_with(|| {
+                groups.push(LinkedList::new());
+                groups.len() - 1
+            });
+
+            let list = &mut groups[index];
+            unsafe { self.unlink_node(node) };
+            list.link_node(list.foot, node, None);
         }
-    }
 
-    pub fn push(&mut self, data: T) {
-        let new_head = LinkedList::new_node_link(data);
+        // Put the keys back in the order of their groups
+        let mut keys = indices.into_iter().collect::<Vec<_>>();
+        keys.sort_unstable_by_key(|(_, index)| *index);
 
-        if let Some(head) = self.next {
-            unsafe {
-                // Previous of self.head should be set to new_head
-                previous!(head) = Some(new_head);
+        zip(keys, groups).map(|((key, _), list)| (key, list)).collect()
+    }
 
-                // Update new head node next to point at old head
-                next!(new_head) = Some(head);
-            }
+    pub fn insert_sorted(&mut self, data: T)
+    where
+        T: Ord,
+    {
+        self.insert_sorted_by(data, T::cmp)
+    }
 
-            // Are we at the start of the list?
-            if self.next == self.list.head {
-                self.list.head = Some(new_head);
-            }
-        }
-        else if let Some(foot) = self.previous {
-            unsafe {
-                // Previous of new_foot should be set to foot
-                previous!(new_head) = Some(foot);
+    pub fn insert_sorted_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, data: T, mut f: F) {
+        let key = f(&data);
+        self.insert_sorted_by(data, |a, _| f(a).cmp(&key))
+    }
 
-                // Update foot next to new_foot
-                next!(foot) = Some(new_head);
-            }
+    /// Inserts `data` after any equal elements of the sorted list
+    pub fn insert_sorted_by<F: FnMut(&T, &T) -> Ordering>(&mut self, data: T, mut compare: F) {
+        let mut cursor = self.cursor_mut();
 
-            // Are we at the end of the list?
-            if self.previous == self.list.foot {
-                self.list.foot = Some(new_head);
+        while let Some(next) = cursor.next_data() {
+            if compare(next, &data) == Ordering::Greater {
+                break;
             }
+            cursor.move_next();
         }
 
-        if self.list.head.is_none() && self.list.foot.is_none() {
-            // If this is the first element, just put it on the list
-            self.list.head = Some(new_head);
-            self.list.foot = Some(new_head);
-        }
+        cursor.push(data);
+    }
 
-        // Update head and length
-        self.list.length += 1;
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b)
     }
 
-    pub fn pop_front(&mut self) -> Option<T> {
-        self.list.head.map(|node| unsafe {
-            // Set head to the current heads next node
-            self.list.head = next!(node);
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b))
+    }
 
-            // Set the new head previous to the old heads previous
-            // I.e. at end of the list its None
-            if let Some(head) = self.list.head {
-                previous!(head) = previous!(node);
+    /// Unlinks consecutive elements for which `same_bucket(element, kept)` is true.
+    ///
+    /// Like `Vec::dedup_by`, `kept` is the element before it which stays in the list.
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+        let Some(mut kept) = self.head else {
+            return;
+        };
+
+        while let Some(node) = next_unsafe!(kept) {
+            let duplicate = unsafe {
+                same_bucket(&mut (*node.as_ptr()).data, &mut (*kept.as_ptr()).data)
+            };
+
+            if duplicate {
+                unsafe {
+                    self.unlink_node(node);
+                    drop(Box::from_raw(node.as_ptr()));
+                }
+            }
+            else {
+                kept = node;
             }
+        }
+    }
 
-            // Restore the node as a box and move its data
-            let boxed_data = Box::from_raw(node.as_ptr()).data;
+    /// Reverses the list by flipping the links of every node
+    pub fn reverse(&mut self) {
+        let mut current = self.head;
 
-            self.list.length -= 1;
+        while let Some(node) = current {
+            unsafe {
+                let node = &mut *node.as_ptr();
+                std::mem::swap(&mut node.next, &mut node.previous);
 
-            // If the length is now 1, we need head to be equal foot
-            // If the length is now 0, we need head and foot to be None
-            if self.list.length <= 1
-            {
-                self.list.foot = self.list.head;
+                // What was next is now previous
+                current = node.previous;
             }
+        }
 
-            boxed_data
-        })
+        std::mem::swap(&mut self.head, &mut self.foot);
     }
 
-    pub fn pop_back(&mut self) -> Option<T> {
-        self.list.foot.map(|node| unsafe {
-            // Set foot to the current foods previous node
-            self.list.foot = previous!(node);
+    /// Rotates the list `n` places to the left, so the element at `n` becomes the head.
+    ///
+    /// # Panics
+    /// If `n` is greater than the length of the list.
+    pub fn rotate_left(&mut self, n: usize) {
+        assert!(n <= self.length, "rotate_left: n ({n}) > len ({})", self.length);
 
-            // Set the new foot next to the old heads next
-            // I.e. at end of the list its None
-            if let Some(foot) = self.list.foot {
-                next!(foot) = next!(node)
-            }
+        if n == 0 || n == self.length {
+            return;
+        }
 
-            // Restore the node as a box and move its data
-            let boxed_data = Box::from_raw(node.as_ptr()).data;
+        let new_head = self.node_at(n).unwrap();
 
-            self.list.length -= 1;
+        unsafe {
+            // Close the ring, by linking the foot back to the head
+            let (head, foot) = (self.head.unwrap(), self.foot.unwrap());
+            next!(foot) = Some(head);
+            previous!(head) = Some(foot);
+
+            // And open it again in front of the new head
+            let new_foot = previous!(new_head).unwrap();
+            next!(new_foot) = None;
+            previous!(new_head) = None;
+
+            self.head = Some(new_head);
+            self.foot = Some(new_foot);
+        }
+    }
 
-            // If the length is now 1, we need head to be equal foot
-            // If the length is now 0, we need head and foot to be None
-            if self.list.length <= 1
-            {
-                self.list.head = self.list.foot;
-            }
+    /// Rotates the list `n` places to the right, so the element at `len - n` becomes the head.
+    ///
+    /// # Panics
+    /// If `n` is greater than the length of the list.
+    pub fn rotate_right(&mut self, n: usize) {
+        assert!(n <= self.length, "rotate_right: n ({n}) > len ({})", self.length);
 
-            boxed_data
-        })
+        self.rotate_left(self.length - n)
     }
-}
-
-pub struct Iter<'a, T> {
-    current_front: NodeLink<T>,
-    current_back: NodeLink<T>,
-    length: usize,
-    _phantom: PhantomData<&'a T>,
-}
 
-impl<'a, T> Iterator for Iter<'a, T> {
-    type Item = &'a T;
+    /// Swaps the elements at `i` and `j` by relinking their nodes.
+    ///
+    /// # Panics
+    /// If either index is out of bounds.
+    pub fn swap(&mut self, i: usize, j: usize) {
+        assert!(i < self.length, "swap: index i ({i}) out of bounds for len {}", self.length);
+        assert!(j < self.length, "swap: index j ({j}) out of bounds for len {}", self.length);
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.current_front.map(|node| {
-            self.current_front = next_unsafe!(node);
+        if i == j {
+            return;
+        }
 
-            data_unsafe!(node)
-        })
+        let a = self.node_at(i).unwrap();
+        let b = self.node_at(j).unwrap();
+        unsafe { self.swap_nodes(a, b) };
     }
-}
 
-impl<'a, T> ExactSizeIterator for Iter<'a, T> {
-    fn len(&self) -> usize {
-        self.length
+    /// Splits the list in two at `at`, returning everything from `at` on
+    #[cfg_attr(not(feature = "rayon"), allow(dead_code))]
+    pub(crate) fn split_off(&mut self, at: usize) -> LinkedList<T> {
+        let (front, back, length) = self.range_nodes(at ..);
+
+        match (front, back) {
+            (Some(front), Some(back)) => unsafe { self.detach(front, back, length) },
+            _ => LinkedList::new(),
+        }
     }
-}
 
-impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
-    fn next_back(&mut self) -> Option<Self::Item> {
-        self.current_back.map(|node| {
-            self.current_back = previous_unsafe!(node);
+    /// Finds the first and last node of `range`, and the number of nodes in it
+    fn range_nodes<R: RangeBounds<usize>>(&self, range: R) -> (NodeLink<T>, NodeLink<T>, usize) {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1)
+                .unwrap_or_else(|| panic!("range start index {start} excluded is out of range for usize")),
+            Bound::Unbounded => 0,
+        };
 
-            data_unsafe!(node)
-        })
-    }
-}
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1)
+                .unwrap_or_else(|| panic!("range end index {end} included is out of range for usize")),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.length,
+        };
 
-pub struct IntoIter<T> {
-    list: LinkedList<T>,
-}
+        assert!(start <= end, "range start index {start} is after end index {end}");
+        assert!(end <= self.length, "range end index {end} out of range for list of length {}", self.length);
 
-impl<T> Iterator for IntoIter<T> {
-    type Item = T;
+        if start == end {
+            return (None, None, 0);
+        }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.list.pop_front()
+        (self.node_at(start), self.node_at(end - 1), end - start)
     }
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
+    /// Unlinks the `length` nodes from `front` to `back` and makes a new list of them.
+    ///
+    /// # Safety
+    /// `front` must be a node of this list, with `back` `length - 1` nodes after it.
+    pub(crate) unsafe fn detach(&mut self, front: NodeLinkSome<T>, back: NodeLinkSome<T>, length: usize) -> LinkedList<T> {
+        let previous = previous!(front);
+        let next = next!(back);
+
+        // Close the gap in this list
+        match previous {
+            Some(previous) => next!(previous) = next,
+            None => self.head = next,
+        }
+
+        match next {
+            Some(next) => previous!(next) = previous,
+            None => self.foot = previous,
+        }
+
+        self.length -= length;
+
+        // And cut the loose ends of the detached nodes
+        previous!(front) = None;
+        next!(back) = None;
+
+        LinkedList { head: Some(front), foot: Some(back), length }
+    }
+
+    /// Links all nodes of `other` in between `previous` and `next`, the reverse of `detach`.
+    ///
+    /// # Safety
+    /// `previous` and `next` must be adjacent nodes of this list, or None at its ends.
+    pub(crate) unsafe fn attach(&mut self, previous: NodeLink<T>, other: LinkedList<T>, next: NodeLink<T>) {
+        let (Some(front), Some(back), length) = other.into_parts() else {
+            return;
+        };
+
+        previous!(front) = previous;
+        next!(back) = next;
+
+        match previous {
+            Some(previous) => next!(previous) = Some(front),
+            None => self.head = Some(front),
+        }
+
+        match next {
+            Some(next) => previous!(next) = Some(back),
+            None => self.foot = Some(back),
+        }
+
+        self.length += length;
+    }
+
+    /// Finds the node at `index`, walking from the nearer end
+    fn node_at(&self, index: usize) -> NodeLink<T> {
+        if index >= self.length {
+            return None;
+        }
+
+        if index < self.length / 2 {
+            let mut current = self.head;
+            (0 .. index).for_each(|_| current = current.and_then(|node| next_unsafe!(node)));
+            current
+        }
+        else {
+            let mut current = self.foot;
+            (index + 1 .. self.length).for_each(|_| current = current.and_then(|node| previous_unsafe!(node)));
+            current
+        }
+    }
+
+    /// Exchanges the positions of two distinct nodes.
+    ///
+    /// # Safety
+    /// `a` and `b` must be different nodes of this list.
+    unsafe fn swap_nodes(&mut self, a: NodeLinkSome<T>, b: NodeLinkSome<T>) {
+        if next!(a) == Some(b) {
+            // Neighbours, so just move b in front of a
+            self.unlink_node(b);
+            self.link_node(previous!(a), b, Some(a));
+        }
+        else if next!(b) == Some(a) {
+            self.unlink_node(a);
+            self.link_node(previous!(b), a, Some(b));
+        }
+        else {
+            let (a_previous, a_next) = (previous!(a), next!(a));
+            let (b_previous, b_next) = (previous!(b), next!(b));
+
+            self.unlink_node(a);
+            self.unlink_node(b);
+
+            self.link_node(a_previous, b, a_next);
+            self.link_node(b_previous, a, b_next);
+        }
+    }
+
+    pub(crate) fn head_link(&self) -> NodeLink<T> {
+        self.head
+    }
+
+    pub(crate) fn foot_link(&self) -> NodeLink<T> {
+        self.foot
+    }
+
+    /// Hands over the nodes of the list without dropping them
+    pub(crate) fn into_parts(self) -> (NodeLink<T>, NodeLink<T>, usize) {
+        let list = ManuallyDrop::new(self);
+        (list.head, list.foot, list.length)
+    }
+
+    /// Takes ownership of a chain of nodes from `head` to `foot`.
+    ///
+    /// # Safety
+    /// The nodes must be properly linked, with `length` nodes from `head` to `foot`.
+    pub(crate) unsafe fn from_parts(head: NodeLink<T>, foot: NodeLink<T>, length: usize) -> Self {
+        Self { head, foot, length }
+    }
+
+    /// Links `node` in between `previous` and `next`, which must be adjacent
+    pub(crate) fn link_node(&mut self, previous: NodeLink<T>, node: NodeLinkSome<T>, next: NodeLink<T>) {
+        unsafe {
+            previous!(node) = previous;
+            next!(node) = next;
+        }
+
+        match previous {
+            Some(previous) => unsafe { next!(previous) = Some(node) },
+            None => self.head = Some(node),
+        }
+
+        match next {
+            Some(next) => unsafe { previous!(next) = Some(node) },
+            None => self.foot = Some(node),
+        }
+
+        self.length += 1;
+    }
+
+    /// Unlinks `node` from the list, the caller takes over ownership of it.
+    ///
+    /// # Safety
+    /// `node` must be a node of this list.
+    pub(crate) unsafe fn unlink_node(&mut self, node: NodeLinkSome<T>) {
+        let previous = previous!(node);
+        let next = next!(node);
+
+        match previous {
+            Some(previous) => next!(previous) = next,
+            None => self.head = next,
+        }
+
+        match next {
+            Some(next) => previous!(next) = previous,
+            None => self.foot = previous,
+        }
+
+        previous!(node) = None;
+        next!(node) = None;
+
+        self.length -= 1;
+    }
+
+    /// Points `before` at `node`, or `head` if `before` is None
+    fn link_after(&mut self, before: NodeLink<T>, node: NodeLinkSome<T>) {
+        match before {
+            Some(before) => unsafe { next!(before) = Some(node) },
+            None => self.head = Some(node),
+        }
+    }
+
+    /// Rebuilds `previous` and `foot` by walking the `next` chain from head
+    fn relink_previous(&mut self) {
+        let mut previous_node: NodeLink<T> = None;
+        let mut current = self.head;
+
+        while let Some(node) = current {
+            unsafe {
+                previous!(node) = previous_node;
+                current = next!(node);
+            }
+            previous_node = Some(node);
+        }
+
+        self.foot = previous_node;
+    }
+}
+
+/// Restores the `previous` links of the list when dropped, also on panic
+struct RelinkGuard<'a, T> {
+    list: &'a mut LinkedList<T>,
+}
+
+impl<'a, T> Drop for RelinkGuard<'a, T> {
+    fn drop(&mut self) {
+        self.list.relink_previous();
+    }
+}
+
+// The list owns its nodes, so it can be sent and shared just like the elements can
+unsafe impl<T: Send> Send for LinkedList<T> {}
+unsafe impl<T: Sync> Sync for LinkedList<T> {}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        zip(self.iter(), other.iter()).all(|(x,y)| {
+            x == y
+        })
+    }
+}
+
+impl<T> Index<usize> for LinkedList<T> {
+    type Output = T;
+
+    /// # Panics
+    /// If `index` is out of bounds.
+    fn index(&self, index: usize) -> &T {
+        self.try_get(index).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl<T> IndexMut<usize> for LinkedList<T> {
+    /// # Panics
+    /// If `index` is out of bounds.
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.length;
+        self.get_mut(index).unwrap_or_else(|| panic!("{}", Error::IndexOutOfBounds { index, len }))
+    }
+}
+
+impl<T> From<Vec<T>> for LinkedList<T> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect::<LinkedList<T>>()
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<U: IntoIterator<Item = T>>(iter: U) -> Self {
+        let mut list = LinkedList::<T>::new();
+        iter.into_iter().for_each(|x| list.push_back(x));
+
+        list
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format_str = self.iter()
+            .map(|x| format!("{}, ", x))
+            .collect::<String>();
+
+        write!(f, "{}", format_str)
+    }
+}
+
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        while self.pop_back().is_some() {}
+    }
+}
+
+/// A cursor resting in one of the `len + 1` gaps between the elements of a `LinkedList`,
+/// or on the "ghost" position.
+///
+/// Its index is the number of elements in front of it, from 0 at the front to `len`
+/// at the back. Like std's cursor, it wraps around through a ghost position that sits
+/// between the back and the front: moving next from the back lands on the ghost, and
+/// moving next again lands at the front, and the same the other way around.
+///
+/// The ghost has no index. Its next element is the front of the list and its previous
+/// element is the back, so pushing there appends to the back and removing the next
+/// element pops the front, while the cursor stays on the ghost. Everything in the list
+/// is both after and before the ghost.
+pub struct Cursor<'a, T> {
+    // On the ghost these are the head and the foot
+    next: NodeLink<T>,
+    previous: NodeLink<T>,
+    // The number of elements in front of the cursor, 0 on the ghost
+    index: usize,
+    ghost: bool,
+    list: &'a mut LinkedList<T>,
+}
+
+impl<'a, T> Cursor<'a, T> {
+    /// Gets the number of elements in front of the cursor, or None on the ghost
+    pub fn index(&self) -> Option<usize> {
+        (!self.ghost).then_some(self.index)
+    }
+
+    pub fn is_ghost(&self) -> bool {
+        self.ghost
+    }
+
+    /// Checks if the cursor is at the front, with no element before it
+    pub fn at_front(&self) -> bool {
+        !self.ghost && self.previous.is_none()
+    }
+
+    /// Checks if the cursor is at the back, with no element after it
+    pub fn at_back(&self) -> bool {
+        !self.ghost && self.next.is_none()
+    }
+
+    /// Moves the cursor to `index`, walking from its current position, the front or the back,
+    /// whichever is nearest.
+    ///
+    /// # Panics
+    /// If `index` is greater than the length of the list.
+    pub fn seek_to(&mut self, index: usize) {
+        let length = self.list.length;
+        assert!(index <= length, "seek index {index} out of range for list of length {length}");
+
+        if self.ghost {
+            self.move_next();
+        }
+
+        let from_here = self.index.abs_diff(index);
+        if index < from_here {
+            self.next = self.list.head;
+            self.previous = None;
+            self.index = 0;
+        }
+        else if length - index < from_here {
+            self.next = None;
+            self.previous = self.list.foot;
+            self.index = length;
+        }
+
+        while self.index < index {
+            self.move_next();
+        }
+
+        while self.index > index {
+            self.move_previous();
+        }
+    }
+
+    /// Moves the cursor `offset` places, forward if positive and backward if negative.
+    ///
+    /// This is the same as that many calls to `move_next` or `move_previous`, so it wraps
+    /// around through the ghost, but it takes the shortest path to where it ends up.
+    pub fn move_by(&mut self, offset: isize) {
+        // The gaps are places 0 to len, and the ghost is the place after len
+        let places = self.list.length + 1;
+        let place = if self.ghost { places } else { self.index };
+
+        let place = (place as i128 + offset as i128).rem_euclid(places as i128 + 1) as usize;
+        if place == places {
+            self.move_to_ghost();
+        }
+        else {
+            self.seek_to(place);
+        }
+    }
+
+    /// Gets the element after the cursor.
+    ///
+    /// The reference borrows the cursor, so it cannot outlive the element being removed:
+    ///
+    /// ```compile_fail
+    /// use doubly_linked_list::linked_list::LinkedList;
+    ///
+    /// let mut linked_list = LinkedList::from(vec![String::from("foo")]);
+    /// let mut cursor = linked_list.cursor_mut();
+    /// let next = cursor.next_data().unwrap();
+    /// cursor.remove_next();
+    /// assert_eq!(next.len(), 3);
+    /// ```
+    pub fn next_data(&self) -> Option<&T> {
+        self.next.map(|node| data_unsafe!(node))
+    }
+
+    /// Gets the element before the cursor.
+    ///
+    /// Like `next_data`, the reference cannot outlive the element being removed:
+    ///
+    /// ```compile_fail
+    /// use doubly_linked_list::linked_list::LinkedList;
+    ///
+    /// let mut linked_list = LinkedList::from(vec![String::from("foo")]);
+    /// let mut cursor = linked_list.cursor_mut();
+    /// cursor.move_next();
+    /// let previous = cursor.previous_data().unwrap();
+    /// cursor.pop_back();
+    /// assert_eq!(previous.len(), 3);
+    /// ```
+    pub fn previous_data(&self) -> Option<&T> {
+        self.previous.map(|node| data_unsafe!(node))
+    }
+
+    pub fn next_data_mut(&mut self) -> Option<&mut T> {
+        self.next.map(|node| unsafe { &mut (*node.as_ptr()).data })
+    }
+
+    /// Gets the list the cursor is in, to look at while holding on to the cursor
+    pub fn as_list(&self) -> &LinkedList<T> {
+        self.list
+    }
+
+    /// Iterates the elements after the cursor, from the next one to the back
+    pub fn iter_after(&self) -> Range<'_, T> {
+        unsafe { Range::from_nodes(self.next, self.list.foot, self.list.length - self.index) }
+    }
+
+    /// Iterates the elements before the cursor, from the front up to the previous one.
+    ///
+    /// Reverse it to walk back from the cursor.
+    pub fn iter_before(&self) -> Range<'_, T> {
+        unsafe { Range::from_nodes(self.list.head, self.previous, self.before()) }
+    }
+
+    /// Gets a view of the elements between the cursor and `index`, on either side of it.
+    ///
+    /// This stands in for a view between two cursors: a `Cursor` borrows the list mutably,
+    /// so there can't be two of them on one list, and the other end is an index instead.
+    ///
+    /// On the ghost this is the elements in front of `index`.
+    ///
+    /// # Panics
+    /// If `index` is greater than the length of the list.
+    pub fn slice_to(&self, index: usize) -> ListSlice<'_, T> {
+        let (front, back, length) = self.span_to(index);
+
+        unsafe { ListSlice::from_nodes(front, back, length) }
+    }
+
+    /// Turns the cursor into a mutable view of the elements between it and `index`.
+    ///
+    /// This takes the cursor, as sorting or reversing the view can relink the nodes next to it.
+    /// Like `slice_to`, the other end is an index rather than a second cursor.
+    ///
+    /// # Panics
+    /// If `index` is greater than the length of the list.
+    pub fn into_slice_mut(self, index: usize) -> ListSliceMut<'a, T> {
+        let (front, back, length) = self.span_to(index);
+
+        unsafe { ListSliceMut::from_nodes(self.list, front, back, length) }
+    }
+
+    /// Finds the first and last node between the cursor and `index`, walking from the cursor
+    fn span_to(&self, index: usize) -> (NodeLink<T>, NodeLink<T>, usize) {
+        let length = self.list.length;
+        assert!(index <= length, "slice index {index} out of range for list of length {length}");
+
+        if index >= self.index {
+            // Walking forward from the ghost is the same as from the front
+            let mut back = if self.ghost { None } else { self.previous };
+            (self.index .. index).for_each(|_| back = match back {
+                Some(node) => next_unsafe!(node),
+                None => self.list.head,
+            });
+
+            (self.next, back, index - self.index)
+        }
+        else {
+            let mut front = self.next;
+            (index .. self.index).for_each(|_| front = match front {
+                Some(node) => previous_unsafe!(node),
+                None => self.list.foot,
+            });
+
+            (front, self.previous, self.index - index)
+        }
+    }
+
+    /// Removes the elements after the cursor for as long as they match `predicate`,
+    /// returning them as a list of their own
+    pub fn remove_while<P: FnMut(&T) -> bool>(&mut self, mut predicate: P) -> LinkedList<T> {
+        let mut back = None;
+        let mut length = 0;
+        let mut current = self.next;
+
+        while let Some(node) = current {
+            if !predicate(data_unsafe!(node)) {
+                break;
+            }
+
+            back = Some(node);
+            length += 1;
+            current = next_unsafe!(node);
+        }
+
+        match (self.next, back) {
+            (Some(front), Some(back)) => {
+                self.next = current;
+                let removed = unsafe { self.list.detach(front, back, length) };
+
+                // The back may have gone as well
+                if self.ghost {
+                    self.previous = self.list.foot;
+                }
+
+                removed
+            }
+            _ => LinkedList::new(),
+        }
+    }
+
+    /// Removes every element after the cursor in O(1), returning them as a list of their own
+    pub fn drain_after(&mut self) -> LinkedList<T> {
+        match (self.next, self.list.foot) {
+            (Some(front), Some(back)) => {
+                self.next = None;
+                if self.ghost {
+                    self.previous = None;
+                }
+
+                unsafe { self.list.detach(front, back, self.list.length - self.index) }
+            }
+            _ => LinkedList::new(),
+        }
+    }
+
+    /// Removes every element before the cursor in O(1), returning them as a list of their own.
+    ///
+    /// The cursor ends up at the front, or stays on the ghost.
+    pub fn drain_before(&mut self) -> LinkedList<T> {
+        match (self.list.head, self.previous) {
+            (Some(front), Some(back)) => {
+                let length = self.before();
+                self.previous = None;
+                self.index = 0;
+                if self.ghost {
+                    self.next = None;
+                }
+
+                unsafe { self.list.detach(front, back, length) }
+            }
+            _ => LinkedList::new(),
+        }
+    }
+
+    /// Moves past the next element, from the back onto the ghost, and from the ghost to the front
+    pub fn move_next(&mut self) {
+        if self.ghost {
+            self.ghost = false;
+            self.next = self.list.head;
+            self.previous = None;
+            self.index = 0;
+        }
+        else if let Some(next) = self.next {
+            // Set next to nexts next
+            self.next = next_unsafe!(next);
+
+            // Set previous to next
+            self.previous = Some(next);
+            self.index += 1;
+        }
+        else {
+            self.move_to_ghost();
+        }
+    }
+
+    /// Moves back past the previous element, from the front onto the ghost, and from the ghost to the back
+    pub fn move_previous(&mut self) {
+        if self.ghost {
+            self.ghost = false;
+            self.next = None;
+            self.previous = self.list.foot;
+            self.index = self.list.length;
+        }
+        else if let Some(previous) = self.previous {
+            // Set previous to previous' previous
+            self.previous = previous_unsafe!(previous);
+
+            // Set next to previous
+            self.next = Some(previous);
+            self.index -= 1;
+        }
+        else {
+            self.move_to_ghost();
+        }
+    }
+
+    fn move_to_ghost(&mut self) {
+        self.ghost = true;
+        self.next = self.list.head;
+        self.previous = self.list.foot;
+        self.index = 0;
+    }
+
+    /// The number of elements before the cursor, which on the ghost is all of them
+    fn before(&self) -> usize {
+        if self.ghost { self.list.length } else { self.index }
+    }
+
+    /// Inserts `data` before the cursor, so it becomes the previous element.
+    ///
+    /// On the ghost this pushes to the back of the list.
+    pub fn push(&mut self, data: T) {
+        let new_node = LinkedList::new_node_link(data);
+
+        if self.ghost {
+            self.list.link_node(self.list.foot, new_node, None);
+            self.next = self.list.head;
+            self.previous = Some(new_node);
+
+            return;
+        }
+
+        // Link the new node in between previous and next
+        self.list.link_node(self.previous, new_node, self.next);
+
+        // The cursor now sits right after the new node
+        self.previous = Some(new_node);
+        self.index += 1;
+    }
+
+    /// Removes the element after the cursor, the one after that becomes the next
+    pub fn remove_next(&mut self) -> Option<T> {
+        self.next.map(|node| unsafe {
+            self.next = next!(node);
+            self.list.unlink_node(node);
+
+            // On the ghost, the front may have been the back too
+            if self.previous == Some(node) {
+                self.previous = None;
+            }
+
+            Box::from_raw(node.as_ptr()).data
+        })
+    }
+
+    /// Swaps the node after the cursor with the one following it.
+    ///
+    /// The cursor stays put, so the next element is now what was the one after.
+    pub fn swap_with_next(&mut self) {
+        if let Some(next) = self.next {
+            if let Some(next_next) = next_unsafe!(next) {
+                unsafe { self.list.swap_nodes(next, next_next) };
+                self.next = Some(next_next);
+
+                // On the ghost, the back may have moved
+                if self.ghost {
+                    self.previous = self.list.foot;
+                }
+            }
+        }
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.list.head.map(|node| unsafe {
+            // Set head to the current heads next node
+            self.list.head = next!(node);
+
+            // Set the new head previous to the old heads previous
+            // I.e. at end of the list its None
+            if let Some(head) = self.list.head {
+                previous!(head) = previous!(node);
+            }
+
+            // Keep the cursor off the removed node, it was either right before or after it
+            if self.previous == Some(node) {
+                self.previous = None;
+            }
+            if self.next == Some(node) {
+                self.next = self.list.head;
+            }
+            self.index = self.index.saturating_sub(1);
+
+            // Restore the node as a box and move its data
+            let boxed_data = Box::from_raw(node.as_ptr()).data;
+
+            self.list.length -= 1;
+
+            // If the length is now 1, we need head to be equal foot
+            // If the length is now 0, we need head and foot to be None
+            if self.list.length <= 1
+            {
+                self.list.foot = self.list.head;
+            }
+
+            boxed_data
+        })
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.list.foot.map(|node| unsafe {
+            // Set foot to the current foods previous node
+            self.list.foot = previous!(node);
+
+            // Set the new foot next to the old heads next
+            // I.e. at end of the list its None
+            if let Some(foot) = self.list.foot {
+                next!(foot) = next!(node)
+            }
+
+            // Keep the cursor off the removed node, it was either right before or after it
+            if self.next == Some(node) {
+                self.next = None;
+            }
+            if self.previous == Some(node) {
+                self.previous = self.list.foot;
+                if !self.ghost {
+                    self.index -= 1;
+                }
+            }
+
+            // Restore the node as a box and move its data
+            let boxed_data = Box::from_raw(node.as_ptr()).data;
+
+            self.list.length -= 1;
+
+            // If the length is now 1, we need head to be equal foot
+            // If the length is now 0, we need head and foot to be None
+            if self.list.length <= 1
+            {
+                self.list.head = self.list.foot;
+            }
+
+            boxed_data
+        })
+    }
+}
+
+pub struct Iter<'a, T> {
+    current_front: NodeLink<T>,
+    current_back: NodeLink<T>,
+    // The elements not yet handed out from either end, so the ends stop where they meet
+    remaining: usize,
+    _phantom: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    /// Peeks at the element `next` would hand out, without moving
+    pub fn peek_next(&self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.current_front.map(|node| data_unsafe!(node))
+    }
+
+    /// Peeks at the element `next_back` would hand out, without moving
+    pub fn peek_back(&self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.current_back.map(|node| data_unsafe!(node))
+    }
+
+    /// Gets a view of the elements not yet handed out from either end
+    pub fn remaining_as_list_view(&self) -> ListSlice<'a, T> {
+        unsafe { ListSlice::from_nodes(self.current_front, self.current_back, self.remaining) }
+    }
+
+    /// Slides a window of `size` elements over the remaining elements, one element at a time.
+    ///
+    /// Every window is a view into the list, so this takes O(1) per window.
+    ///
+    /// # Panics
+    /// If `size` is 0.
+    pub fn windows(self, size: usize) -> Windows<'a, T> {
+        assert!(size > 0, "window size must be greater than 0");
+
+        let count = (self.remaining + 1).saturating_sub(size);
+
+        // Find the back of the first window, if there are enough elements for one
+        let mut back = None;
+        if count > 0 {
+            back = self.current_front;
+            for _ in 1 .. size {
+                back = back.and_then(|node| next_unsafe!(node));
+            }
+        }
+
+        Windows { front: self.current_front, back, size, count, _phantom: PhantomData }
+    }
+
+    /// Splits the remaining elements into views of `size` elements,
+    /// of which the last one may be shorter.
+    ///
+    /// # Panics
+    /// If `size` is 0.
+    pub fn chunks(self, size: usize) -> Chunks<'a, T> {
+        assert!(size > 0, "chunk size must be greater than 0");
+
+        Chunks { front: self.current_front, size, remaining: self.remaining, _phantom: PhantomData }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Stop when front and back have met, so no element is handed out twice
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.current_front.map(|node| {
+            self.current_front = next_unsafe!(node);
+            self.remaining -= 1;
+
+            data_unsafe!(node)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.current_back.map(|node| {
+            self.current_back = previous_unsafe!(node);
+            self.remaining -= 1;
+
+            data_unsafe!(node)
+        })
+    }
+}
+
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+/// The overlapping windows of a `LinkedList`, see `Iter::windows`
+pub struct Windows<'a, T> {
+    front: NodeLink<T>,
+    // The last node of the next window, or None once there is no whole window left
+    back: NodeLink<T>,
+    size: usize,
+    // The windows left, as the iterator they came from may have been cut short at the back
+    count: usize,
+    _phantom: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = ListSlice<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let back = self.back?;
+        let window = unsafe { ListSlice::from_nodes(self.front, Some(back), self.size) };
+
+        // Both ends of the window move up one node
+        self.front = self.front.and_then(|node| next_unsafe!(node));
+        self.back = next_unsafe!(back);
+        self.count -= 1;
+
+        Some(window)
+    }
+}
+
+/// The non-overlapping chunks of a `LinkedList`, see `Iter::chunks`
+pub struct Chunks<'a, T> {
+    front: NodeLink<T>,
+    size: usize,
+    // The elements left to chunk, which may stop short of the back of the list
+    remaining: usize,
+    _phantom: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = ListSlice<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let front = self.front?;
+
+        let length = self.size.min(self.remaining);
+        let mut back = front;
+        for _ in 1 .. length {
+            back = next_unsafe!(back)?;
+        }
+
+        self.front = next_unsafe!(back);
+        self.remaining -= length;
+
+        Some(unsafe { ListSlice::from_nodes(Some(front), Some(back), length) })
+    }
+}
+
+pub struct IterMut<'a, T> {
+    current_front: NodeLink<T>,
+    current_back: NodeLink<T>,
+    length: usize,
+    _phantom: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> IterMut<'a, T> {
+    /// Mutably iterates the `length` nodes from `front` to `back`.
+    ///
+    /// # Safety
+    /// `back` must be `length - 1` nodes after `front`, in a list mutably borrowed for `'a`,
+    /// and no other iterator may hand out the same nodes.
+    #[cfg_attr(not(feature = "rayon"), allow(dead_code))]
+    pub(crate) unsafe fn from_nodes(front: NodeLink<T>, back: NodeLink<T>, length: usize) -> Self {
+        Self { current_front: front, current_back: back, length, _phantom: PhantomData }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Stop when front and back have met, so no element is handed out twice
+        if self.length == 0 {
+            return None;
+        }
+
+        self.current_front.map(|node| unsafe {
+            self.current_front = next!(node);
+            self.length -= 1;
+
+            &mut (*node.as_ptr()).data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.length, Some(self.length))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {
+    fn len(&self) -> usize {
+        self.length
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.length == 0 {
+            return None;
+        }
+
+        self.current_back.map(|node| unsafe {
+            self.current_back = previous!(node);
+            self.length -= 1;
+
+            &mut (*node.as_ptr()).data
+        })
+    }
+}
+
+pub struct Range<'a, T> {
+    current_front: NodeLink<T>,
+    current_back: NodeLink<T>,
+    length: usize,
+    _phantom: PhantomData<&'a T>,
+}
+
+impl<'a, T> Range<'a, T> {
+    /// Iterates the `length` nodes from `front` to `back`.
+    ///
+    /// # Safety
+    /// `back` must be `length - 1` nodes after `front`, in a list borrowed for `'a`.
+    pub(crate) unsafe fn from_nodes(front: NodeLink<T>, back: NodeLink<T>, length: usize) -> Self {
+        Self { current_front: front, current_back: back, length, _phantom: PhantomData }
+    }
+}
+
+impl<'a, T> Iterator for Range<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Stop at the end of the range, not the end of the list
+        if self.length == 0 {
+            return None;
+        }
+
+        self.current_front.map(|node| {
+            self.current_front = next_unsafe!(node);
+            self.length -= 1;
+
+            data_unsafe!(node)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.length, Some(self.length))
+    }
+}
+
+impl<'a, T> Clone for Range<'a, T> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Range<'a, T> {
+    fn len(&self) -> usize {
+        self.length
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Range<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.length == 0 {
+            return None;
+        }
+
+        self.current_back.map(|node| {
+            self.current_back = previous_unsafe!(node);
+            self.length -= 1;
+
+            data_unsafe!(node)
+        })
+    }
+}
+
+pub struct Drain<'a, T> {
+    // The drained nodes are already detached from the borrowed list
+    list: LinkedList<T>,
+    _phantom: PhantomData<&'a mut LinkedList<T>>,
+}
+
+impl<'a, T> Iterator for Drain<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Drain<'a, T> {
+    fn len(&self) -> usize {
+        self.list.length
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Drain<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+/// The pieces of a `LinkedList` split in between neighbours, see `LinkedList::split_when`
+pub struct SplitWhen<T, P> {
+    // What is left to split, the pieces are detached from its front
+    list: LinkedList<T>,
+    predicate: P,
+}
+
+impl<T, P: FnMut(&T, &T) -> bool> Iterator for SplitWhen<T, P> {
+    type Item = LinkedList<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let front = self.list.head?;
+
+        // Find the last node before the next split
+        let mut back = front;
+        let mut length = 1;
+        while let Some(next) = next_unsafe!(back) {
+            if (self.predicate)(data_unsafe!(back), data_unsafe!(next)) {
+                break;
+            }
+
+            back = next;
+            length += 1;
+        }
+
+        Some(unsafe { self.list.detach(front, back, length) })
+    }
+}
+
+pub struct IntoIter<T> {
+    list: LinkedList<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
         (self.list.len(), Some(self.list.len()))
     }
-}
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {
+    fn len(&self) -> usize {
+        self.list.length
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty() {
+        let linked_list = LinkedList::<u32>::new();
+        assert!(linked_list.is_empty());
+        assert_eq!(linked_list.len(), 0);
+    }
+
+    #[test]
+    fn test_push_front() {
+        let mut linked_list = LinkedList::new();
+
+        linked_list.push_front(1337);
+        assert_eq!(linked_list.len(), 1);
+        linked_list.push_front(42);
+        assert_eq!(linked_list.len(), 2);
+    }
+
+    #[test]
+    fn test_push_back() {
+        let mut linked_list = LinkedList::new();
+
+        linked_list.push_back(1337);
+        assert_eq!(linked_list.len(), 1);
+        linked_list.push_back(42);
+        assert_eq!(linked_list.len(), 2);
+    }
+
+    #[test]
+    fn test_partial_eq() {
+        let mut linked_list = LinkedList::new();
+        linked_list.push_front(1337);
+        linked_list.push_front(42);
+
+        let mut other_linked_list = LinkedList::new();
+        other_linked_list.push_front(1337);
+        other_linked_list.push_front(42);
+
+        assert_eq!(linked_list, other_linked_list);
+        other_linked_list.push_front(666);
+
+        assert_ne!(linked_list, other_linked_list);
+    }
+
+    #[test]
+    fn test_display() {
+        let mut linked_list = LinkedList::new();
+        assert_eq!(format!("{linked_list}"), "");
+
+        linked_list.push_front(1337);
+        linked_list.push_front(42);
+        linked_list.push_front(666);
+
+        assert_eq!(format!("{linked_list}"), "666, 42, 1337, ");
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut linked_list = LinkedList::new();
+        linked_list.push_front(1337);
+        linked_list.push_front(42);
+        linked_list.push_back(666);
+
+        let mut linked_list_iter = linked_list.iter();
+        assert_eq!(linked_list_iter.next(), Some(&42));
+        assert_eq!(linked_list_iter.next(), Some(&1337));
+        assert_eq!(linked_list_iter.next(), Some(&666));
+        assert_eq!(linked_list_iter.next(), None);
+    }
+
+    #[test]
+    fn test_iter_double_ended() {
+        let mut linked_list = LinkedList::new();
+        linked_list.push_front(1337);
+        linked_list.push_front(42);
+        linked_list.push_front(666);
+
+        let mut linked_list_iter = linked_list.iter();
+        assert_eq!(linked_list_iter.len(), 3);
+        assert_eq!(linked_list_iter.next_back(), Some(&1337));
+        assert_eq!(linked_list_iter.next(), Some(&666));
+        assert_eq!(linked_list_iter.len(), 1);
+        assert_eq!(linked_list_iter.next_back(), Some(&42));
+
+        // The ends have met
+        assert_eq!(linked_list_iter.size_hint(), (0, Some(0)));
+        assert_eq!(linked_list_iter.next(), None);
+        assert_eq!(linked_list_iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut linked_list = LinkedList::from(vec![1337, 42, 666]);
+
+        linked_list.iter_mut().for_each(|x| *x += 1);
+        assert_eq!(linked_list, LinkedList::from(vec![1338, 43, 667]));
+
+        let mut linked_list_iter = linked_list.iter_mut();
+        assert_eq!(linked_list_iter.len(), 3);
+        assert_eq!(linked_list_iter.next_back(), Some(&mut 667));
+        assert_eq!(linked_list_iter.next(), Some(&mut 1338));
+        assert_eq!(linked_list_iter.next_back(), Some(&mut 43));
+        assert_eq!(linked_list_iter.next(), None);
+        assert_eq!(linked_list_iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_into_iter_double_ended() {
+        let mut linked_list = LinkedList::new();
+        linked_list.push_front(1337);
+        linked_list.push_front(42);
+        linked_list.push_front(666);
+
+        let mut linked_list_iter = linked_list.into_iter();
+        assert_eq!(linked_list_iter.next_back(), Some(1337));
+        assert_eq!(linked_list_iter.next(), Some(666));
+        assert_eq!(linked_list_iter.next_back(), Some(42));
+        assert_eq!(linked_list_iter.next(), None);
+        assert_eq!(linked_list_iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let vec = vec![1337, 42, 666];
+
+        let mut linked_list_iter = LinkedList::from_iter(vec.clone())
+            .into_iter();
+        assert_eq!(linked_list_iter.next(), Some(1337));
+        assert_eq!(linked_list_iter.next(), Some(42));
+        assert_eq!(linked_list_iter.next(), Some(666));
+        assert_eq!(linked_list_iter.next(), None);
+    }
+
+    #[test]
+    fn test_from_vec() {
+        let vec = vec![1337, 42, 666];
+
+        let mut linked_list_iter = LinkedList::from(vec)
+            .into_iter();
+        assert_eq!(linked_list_iter.next(), Some(1337));
+        assert_eq!(linked_list_iter.next(), Some(42));
+        assert_eq!(linked_list_iter.next(), Some(666));
+        assert_eq!(linked_list_iter.next(), None);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let mut linked_list = LinkedList::new();
+        linked_list.push_front(1337);
+        linked_list.push_front(42);
+        linked_list.push_back(666);
+
+        let mut linked_list_iter = linked_list.into_iter();
+        assert_eq!(linked_list_iter.size_hint(), (3, Some(3)));
+        assert_eq!(linked_list_iter.next(), Some(42));
+        assert_eq!(linked_list_iter.next(), Some(1337));
+        assert_eq!(linked_list_iter.next(), Some(666));
+        assert_eq!(linked_list_iter.next(), None);
+    }
+
+    #[test]
+    fn test_drop() {
+        let mut linked_list = LinkedList::new();
+        linked_list.push_front(1337);
+        linked_list.push_front(42);
+        linked_list.push_back(666);
+
+        drop(linked_list);
+    }
+
+    #[test]
+    fn test_get() {
+        let mut linked_list = LinkedList::new();
+        linked_list.push_front(1337);
+        linked_list.push_front(42);
+        linked_list.push_back(666);
+
+        assert_eq!(linked_list.get(0), Some(&42));
+        assert_eq!(linked_list.get(1), Some(&1337));
+        assert_eq!(linked_list.get(2), Some(&666));
+        assert_eq!(linked_list.get(3), None);
+    }
+
+    #[test]
+    fn test_index() {
+        let mut linked_list = LinkedList::from(vec![1337, 42, 666]);
+        assert_eq!(linked_list[0], 1337);
+        assert_eq!(linked_list[2], 666);
+
+        linked_list[1] += 1;
+        assert_eq!(linked_list.get_mut(1), Some(&mut 43));
+        assert_eq!(linked_list.get_mut(3), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 out of bounds for list of length 3")]
+    fn test_index_out_of_bounds() {
+        let linked_list = LinkedList::from(vec![1337, 42, 666]);
+        let _ = linked_list[3];
+    }
+
+    #[test]
+    #[should_panic(expected = "index 0 out of bounds for list of length 0")]
+    fn test_index_mut_out_of_bounds() {
+        let mut linked_list = LinkedList::<u32>::new();
+        linked_list[0] = 1337;
+    }
+
+    #[test]
+    fn test_try_get_insert_remove() {
+        let mut linked_list = LinkedList::new();
+        assert_eq!(linked_list.try_get(0), Err(Error::IndexOutOfBounds { index: 0, len: 0 }));
+        assert_eq!(linked_list.try_remove(0), Err(Error::IndexOutOfBounds { index: 0, len: 0 }));
+
+        assert_eq!(linked_list.try_insert(0, 42), Ok(()));
+        assert_eq!(linked_list.try_insert(0, 1337), Ok(()));
+        assert_eq!(linked_list.try_insert(2, 666), Ok(()));
+        assert_eq!(linked_list.try_insert(2, 7), Ok(()));
+        assert_eq!(linked_list.try_insert(5, 0), Err(Error::IndexOutOfBounds { index: 5, len: 4 }));
+
+        assert_eq!(linked_list.iter().collect::<Vec<_>>(), vec![&1337, &42, &7, &666]);
+        assert_eq!(linked_list.iter().rev().collect::<Vec<_>>(), vec![&666, &7, &42, &1337]);
+        assert_eq!(linked_list.try_get(2), Ok(&7));
+
+        assert_eq!(linked_list.try_remove(3), Ok(666));
+        assert_eq!(linked_list.try_remove(0), Ok(1337));
+        assert_eq!(linked_list.try_remove(2), Err(Error::IndexOutOfBounds { index: 2, len: 2 }));
+        assert_eq!(linked_list.len(), 2);
+        assert_eq!(linked_list.iter().rev().collect::<Vec<_>>(), vec![&7, &42]);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut linked_list = LinkedList::from(vec![1337, 42, 666, 7, 0]);
+        assert_eq!(linked_list.remove(1), Some(42));
+        assert_eq!(linked_list.remove(3), Some(0));
+        assert_eq!(linked_list.remove(3), None);
+        assert_eq!(linked_list.remove(0), Some(1337));
+
+        assert_eq!(linked_list.len(), 2);
+        assert_eq!(linked_list.iter().collect::<Vec<_>>(), vec![&666, &7]);
+        assert_eq!(linked_list.iter().rev().collect::<Vec<_>>(), vec![&7, &666]);
+    }
+
+    #[test]
+    fn test_contains_position() {
+        let linked_list = LinkedList::from(vec![1337, 42, 666, 42]);
+        assert!(linked_list.contains(&666));
+        assert!(!linked_list.contains(&7));
+
+        assert_eq!(linked_list.position(|x| *x == 42), Some(1));
+        assert_eq!(linked_list.rposition(|x| *x == 42), Some(3));
+        assert_eq!(linked_list.position(|x| *x == 7), None);
+        assert_eq!(linked_list.rposition(|x| *x == 7), None);
+    }
+
+    #[test]
+    fn test_find() {
+        let mut linked_list = LinkedList::from(vec![1337, 42, 666, 43]);
+
+        *linked_list.find_mut(|x| x % 2 == 0).unwrap() += 1000;
+        assert!(linked_list.find_mut(|x| *x == 7).is_none());
+
+        // Parked in front of the match, so it can be edited in place
+        let mut cursor = linked_list.find(|x| *x < 1000 && x % 2 == 0).unwrap();
+        assert_eq!(cursor.previous_data(), Some(&1042));
+        assert_eq!(cursor.next_data(), Some(&666));
+        *cursor.next_data_mut().unwrap() += 1;
+
+        let mut cursor = linked_list.rfind(|x| *x > 1000).unwrap();
+        assert_eq!(cursor.next_data(), Some(&1042));
+        assert_eq!(cursor.remove_next(), Some(1042));
+        assert_eq!(cursor.next_data(), Some(&667));
+        assert_eq!(cursor.previous_data(), Some(&1337));
+
+        let mut cursor = linked_list.rfind(|x| *x == 43).unwrap();
+        assert_eq!(cursor.remove_next(), Some(43));
+        assert_eq!(cursor.remove_next(), None);
+        cursor.push(7);
+
+        assert!(linked_list.find(|x| *x == 1042).is_none());
+        assert!(linked_list.rfind(|x| *x == 1042).is_none());
+        assert_eq!(linked_list.iter().collect::<Vec<_>>(), vec![&1337, &667, &7]);
+        assert_eq!(linked_list.iter().rev().collect::<Vec<_>>(), vec![&7, &667, &1337]);
+    }
+
+    #[test]
+    fn test_error() {
+        let error: Box<dyn std::error::Error> = Box::new(Error::IndexOutOfBounds { index: 1337, len: 42 });
+        assert_eq!(error.to_string(), "index 1337 out of bounds for list of length 42");
+    }
+
+    #[test]
+    fn test_push_front_pop_front() {
+        let mut linked_list = LinkedList::new();
+        linked_list.push_front(String::from("foo"));
+        linked_list.push_front(String::from("bar"));
+        linked_list.push_front(String::from("baz"));
+
+        assert_eq!(linked_list.len(), 3);
+        assert_eq!(linked_list.pop_front().unwrap().as_str(), "baz");
+        assert_eq!(linked_list.len(), 2);
+        assert_eq!(linked_list.pop_front().unwrap().as_str(), "bar");
+        assert_eq!(linked_list.len(), 1);
+        assert_eq!(linked_list.pop_front().unwrap().as_str(), "foo");
+        assert_eq!(linked_list.len(), 0);
+        assert_eq!(linked_list.pop_front(), None);
+
+        assert_eq!(linked_list.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_push_back_pop_back() {
+        let mut linked_list = LinkedList::new();
+        linked_list.push_back(String::from("foo"));
+        linked_list.push_back(String::from("bar"));
+        linked_list.push_back(String::from("baz"));
+
+        assert_eq!(linked_list.pop_back().unwrap().as_str(), "baz");
+        assert_eq!(linked_list.pop_back().unwrap().as_str(), "bar");
+        assert_eq!(linked_list.pop_back().unwrap().as_str(), "foo");
+        assert_eq!(linked_list.pop_back(), None);
+
+        assert_eq!(linked_list.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_push_front_pop_back() {
+        let mut linked_list = LinkedList::new();
+
+        linked_list.push_front(42);
+        linked_list.push_front(1337);
+
+        assert_eq!(linked_list.pop_back(), Some(42));
+        assert!(linked_list.head.is_some());
+        assert!(linked_list.foot.is_some());
+        assert_eq!(linked_list.head, linked_list.foot);
+
+        assert_eq!(linked_list.pop_back(), Some(1337));
+        assert!(linked_list.head.is_none());
+        assert!(linked_list.foot.is_none());
+    }
 
-impl<T> IntoIterator for LinkedList<T> {
-    type Item = T;
-    type IntoIter = IntoIter<T>;
+    #[test]
+    fn test_push_back_pop_front() {
+        let mut linked_list = LinkedList::new();
+        linked_list.push_back(String::from("foo"));
+        linked_list.push_back(String::from("bar"));
+        linked_list.push_back(String::from("baz"));
 
-    fn into_iter(self) -> Self::IntoIter {
-        IntoIter { list: self }
+        assert_eq!(linked_list.pop_front().unwrap().as_str(), "foo");
+        assert_eq!(linked_list.pop_front().unwrap().as_str(), "bar");
+        assert_eq!(linked_list.pop_front().unwrap().as_str(), "baz");
+        assert_eq!(linked_list.pop_front(), None);
+
+        assert_eq!(linked_list.iter().count(), 0);
     }
-}
 
-impl<T> ExactSizeIterator for IntoIter<T> {
-    fn len(&self) -> usize {
-        self.list.length
+    #[test]
+    fn test_pop_none() {
+        let mut linked_list = LinkedList::<u32>::new();
+
+        assert_eq!(linked_list.pop_front(), None);
+        assert_eq!(linked_list.pop_back(), None);
+
+        assert_eq!(linked_list.iter().count(), 0);
     }
-}
 
-impl<T> DoubleEndedIterator for IntoIter<T> {
-    fn next_back(&mut self) -> Option<Self::Item> {
-        self.list.pop_back()
+    #[test]
+    fn test_push_pop_alternate() {
+        let mut linked_list = LinkedList::new();
+        linked_list.push_back(String::from("foo"));
+        linked_list.push_front(String::from("bar"));
+
+        assert_eq!(linked_list.pop_back().unwrap().as_str(), "foo");
+        assert_eq!(linked_list.pop_front().unwrap().as_str(), "bar");
+        assert_eq!(linked_list.pop_front(), None);
+
+        assert_eq!(linked_list.iter().count(), 0);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_push_pop_alternate_2() {
+        let mut linked_list = LinkedList::new();
+        linked_list.push_front(String::from("foo"));
+        linked_list.push_back(String::from("bar"));
+
+        assert_eq!(linked_list.pop_front().unwrap().as_str(), "foo");
+        assert_eq!(linked_list.pop_back().unwrap().as_str(), "bar");
+        assert_eq!(linked_list.pop_back(), None);
+
+        assert_eq!(linked_list.iter().count(), 0);
+    }
 
     #[test]
-    fn test_empty() {
-        let linked_list = LinkedList::<u32>::new();
+    fn test_push_insert() {
+        let mut linked_list = LinkedList::new();
+        linked_list.push_back(String::from("foo"));
+        linked_list.push_back(String::from("bar"));
+        linked_list.insert(1, String::from("baz"));
+
+
+        let mut linked_list_iter = linked_list.iter();
+        assert_eq!(linked_list_iter.next().unwrap().as_str(), "foo");
+        assert_eq!(linked_list_iter.next().unwrap().as_str(), "bar");
+        assert_eq!(linked_list_iter.next().unwrap().as_str(), "baz");
+        assert_eq!(linked_list_iter.next(), None);
+    }
+
+    #[test]
+    fn test_insert_past_the_back() {
+        let mut linked_list = LinkedList::from(vec![1337, 42]);
+
+        linked_list.insert(4, 666);
+        linked_list.insert(usize::MAX, 7);
+        linked_list.insert(0, 0);
+        assert_eq!(linked_list.iter().collect::<Vec<_>>(), vec![&1337, &0, &42, &666, &7]);
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut linked_list = LinkedList::from(vec![5, 3, 8, 1, 9, 2, 7, 4, 6, 0, 3]);
+        assert!(!linked_list.is_sorted());
+
+        linked_list.sort();
+        assert!(linked_list.is_sorted());
+        assert_eq!(linked_list.len(), 11);
+        assert_eq!(linked_list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3, 3, 4, 5, 6, 7, 8, 9]);
+
+        // The previous links must be intact as well
+        assert_eq!(linked_list.iter().rev().copied().collect::<Vec<_>>(), vec![9, 8, 7, 6, 5, 4, 3, 3, 2, 1, 0]);
+        assert_eq!(linked_list.pop_back(), Some(9));
+        assert_eq!(linked_list.pop_front(), Some(0));
+    }
+
+    #[test]
+    fn test_sort_small() {
+        let mut linked_list = LinkedList::<u32>::new();
+        linked_list.sort();
         assert!(linked_list.is_empty());
-        assert_eq!(linked_list.len(), 0);
+        assert!(linked_list.is_sorted());
+
+        linked_list.push_back(42);
+        linked_list.sort();
+        assert_eq!(linked_list.iter().collect::<Vec<_>>(), vec![&42]);
+
+        linked_list.push_front(1337);
+        linked_list.sort();
+        assert_eq!(linked_list.iter().collect::<Vec<_>>(), vec![&42, &1337]);
+        assert_eq!(linked_list.iter().rev().collect::<Vec<_>>(), vec![&1337, &42]);
     }
 
     #[test]
-    fn test_push_front() {
+    fn test_sort_by_key_stable() {
+        let mut linked_list = LinkedList::from(vec![(2, "foo"), (1, "bar"), (2, "baz"), (1, "qux"), (0, "quux")]);
+        linked_list.sort_by_key(|x| x.0);
+
+        assert_eq!(
+            linked_list.iter().map(|x| x.1).collect::<Vec<_>>(),
+            vec!["quux", "bar", "qux", "foo", "baz"]
+        );
+        assert!(linked_list.is_sorted_by(|a, b| a.0 <= b.0));
+    }
+
+    #[test]
+    fn test_sort_by_reverse() {
+        let mut linked_list = (0..100).collect::<LinkedList<_>>();
+        linked_list.sort_unstable_by(|a, b| b.cmp(a));
+
+        assert_eq!(linked_list.iter().copied().collect::<Vec<_>>(), (0..100).rev().collect::<Vec<_>>());
+        assert!(linked_list.is_sorted_by(|a, b| a >= b));
+    }
+
+    #[test]
+    fn test_sort_panic_safe() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let mut linked_list = LinkedList::from(vec![5, 3, 8, 1, 9, 2, 7, 4, 6, 0]);
+
+        let mut calls = 0;
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            linked_list.sort_by(|a, b| {
+                calls += 1;
+                if calls == 12 {
+                    panic!("comparator panicked");
+                }
+                a.cmp(b)
+            })
+        }));
+        assert!(result.is_err());
+
+        // Nothing lost, and both directions agree
+        assert_eq!(linked_list.len(), 10);
+        let mut forward = linked_list.iter().copied().collect::<Vec<_>>();
+        let mut backward = linked_list.iter().rev().copied().collect::<Vec<_>>();
+        backward.reverse();
+        assert_eq!(forward, backward);
+
+        forward.sort();
+        assert_eq!(forward, (0..10).collect::<Vec<_>>());
+
+        // And the list is still usable
+        linked_list.sort();
+        assert_eq!(linked_list.iter().copied().collect::<Vec<_>>(), (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_append() {
+        let mut linked_list = LinkedList::from(vec![1337, 42]);
+        let mut other_linked_list = LinkedList::from(vec![666]);
+
+        linked_list.append(&mut other_linked_list);
+        assert_eq!(linked_list.len(), 3);
+        assert!(other_linked_list.is_empty());
+        assert_eq!(linked_list.iter().rev().collect::<Vec<_>>(), vec![&666, &42, &1337]);
+
+        other_linked_list.append(&mut linked_list);
+        assert_eq!(other_linked_list.len(), 3);
+        assert_eq!(other_linked_list.iter().collect::<Vec<_>>(), vec![&1337, &42, &666]);
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut linked_list = LinkedList::from(vec![1, 4, 5, 9]);
+        let mut other_linked_list = LinkedList::from(vec![0, 2, 4, 10, 11]);
+
+        linked_list.merge(&mut other_linked_list);
+        assert!(other_linked_list.is_empty());
+        assert_eq!(linked_list.len(), 9);
+        assert_eq!(linked_list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 4, 4, 5, 9, 10, 11]);
+        assert_eq!(linked_list.iter().rev().copied().collect::<Vec<_>>(), vec![11, 10, 9, 5, 4, 4, 2, 1, 0]);
+
+        // Merging into an empty list takes everything
+        let mut empty_linked_list = LinkedList::new();
+        empty_linked_list.merge(&mut linked_list);
+        assert!(linked_list.is_empty());
+        assert_eq!(empty_linked_list.len(), 9);
+    }
+
+    #[test]
+    fn test_merge_by_stable() {
+        let mut linked_list = LinkedList::from(vec![(1, "foo"), (2, "foo")]);
+        let mut other_linked_list = LinkedList::from(vec![(1, "bar"), (2, "bar"), (3, "bar")]);
+
+        linked_list.merge_by(&mut other_linked_list, |a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            linked_list.iter().copied().collect::<Vec<_>>(),
+            vec![(1, "foo"), (1, "bar"), (2, "foo"), (2, "bar"), (3, "bar")]
+        );
+    }
+
+    #[test]
+    fn test_insert_sorted() {
         let mut linked_list = LinkedList::new();
+        linked_list.insert_sorted(42);
+        linked_list.insert_sorted(1337);
+        linked_list.insert_sorted(0);
+        linked_list.insert_sorted(666);
+        linked_list.insert_sorted(42);
+
+        assert_eq!(linked_list.len(), 5);
+        assert_eq!(linked_list.iter().copied().collect::<Vec<_>>(), vec![0, 42, 42, 666, 1337]);
+        assert_eq!(linked_list.iter().rev().copied().collect::<Vec<_>>(), vec![1337, 666, 42, 42, 0]);
+    }
+
+    #[test]
+    fn test_insert_sorted_by_key() {
+        let mut linked_list = LinkedList::from(vec![("foo", 1), ("bar", 3)]);
+        linked_list.insert_sorted_by_key(("baz", 3), |x| x.1);
+        linked_list.insert_sorted_by_key(("qux", 2), |x| x.1);
+
+        assert_eq!(linked_list.iter().map(|x| x.0).collect::<Vec<_>>(), vec!["foo", "qux", "bar", "baz"]);
+    }
+
+    #[test]
+    fn test_dedup() {
+        let mut linked_list = LinkedList::from(vec![1, 1, 2, 3, 3, 3, 1, 4, 4]);
+        linked_list.dedup();
+
+        assert_eq!(linked_list.len(), 5);
+        assert_eq!(linked_list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 1, 4]);
+        assert_eq!(linked_list.iter().rev().copied().collect::<Vec<_>>(), vec![4, 1, 3, 2, 1]);
+
+        let mut linked_list = LinkedList::<u32>::new();
+        linked_list.dedup();
+        assert!(linked_list.is_empty());
+    }
+
+    #[test]
+    fn test_dedup_by_key() {
+        let mut linked_list = LinkedList::from(vec![10, 11, 20, 21, 22, 30]);
+        linked_list.dedup_by_key(|x| *x / 10);
+
+        assert_eq!(linked_list.iter().copied().collect::<Vec<_>>(), vec![10, 20, 30]);
+    }
+
+    #[test]
+    fn test_dedup_by() {
+        let mut linked_list = LinkedList::from(vec!["foo", "Foo", "bar", "BAR", "baz"]);
+        linked_list.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+
+        assert_eq!(linked_list.iter().copied().collect::<Vec<_>>(), vec!["foo", "bar", "baz"]);
+    }
+
+    #[test]
+    fn test_cursor_push() {
+        let mut linked_list = LinkedList::from(vec![1337, 666]);
+
+        let mut cursor = linked_list.cursor_mut();
+        cursor.move_next();
+        cursor.push(42);
+        assert_eq!(cursor.previous_data(), Some(&42));
+        assert_eq!(cursor.next_data(), Some(&666));
+
+        assert_eq!(linked_list.len(), 3);
+        assert_eq!(linked_list.iter().collect::<Vec<_>>(), vec![&1337, &42, &666]);
+        assert_eq!(linked_list.iter().rev().collect::<Vec<_>>(), vec![&666, &42, &1337]);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut linked_list = LinkedList::from(vec![1337, 42, 666]);
+        linked_list.reverse();
+
+        assert_eq!(linked_list.len(), 3);
+        assert_eq!(linked_list.iter().collect::<Vec<_>>(), vec![&666, &42, &1337]);
+        assert_eq!(linked_list.iter().rev().collect::<Vec<_>>(), vec![&1337, &42, &666]);
+        assert_eq!(linked_list.pop_front(), Some(666));
+        assert_eq!(linked_list.pop_back(), Some(1337));
+
+        let mut linked_list = LinkedList::<u32>::new();
+        linked_list.reverse();
+        assert!(linked_list.is_empty());
+    }
+
+    #[test]
+    fn test_rotate_left() {
+        let mut linked_list = (0..5).collect::<LinkedList<_>>();
+
+        linked_list.rotate_left(2);
+        assert_eq!(linked_list.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4, 0, 1]);
+        assert_eq!(linked_list.iter().rev().copied().collect::<Vec<_>>(), vec![1, 0, 4, 3, 2]);
+
+        linked_list.rotate_left(4);
+        assert_eq!(linked_list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 0]);
+
+        linked_list.rotate_left(0);
+        linked_list.rotate_left(5);
+        assert_eq!(linked_list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 0]);
+    }
+
+    #[test]
+    fn test_rotate_right() {
+        let mut linked_list = (0..5).collect::<LinkedList<_>>();
+
+        linked_list.rotate_right(2);
+        assert_eq!(linked_list.iter().copied().collect::<Vec<_>>(), vec![3, 4, 0, 1, 2]);
+        assert_eq!(linked_list.iter().rev().copied().collect::<Vec<_>>(), vec![2, 1, 0, 4, 3]);
+        assert_eq!(linked_list.pop_back(), Some(2));
+        assert_eq!(linked_list.pop_front(), Some(3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rotate_out_of_bounds() {
+        let mut linked_list = (0..5).collect::<LinkedList<_>>();
+        linked_list.rotate_left(6);
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut linked_list = (0..6).collect::<LinkedList<_>>();
+
+        // Neighbours
+        linked_list.swap(1, 2);
+        assert_eq!(linked_list.iter().copied().collect::<Vec<_>>(), vec![0, 2, 1, 3, 4, 5]);
+        linked_list.swap(2, 1);
+        assert_eq!(linked_list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+
+        // Head and foot
+        linked_list.swap(0, 5);
+        assert_eq!(linked_list.iter().copied().collect::<Vec<_>>(), vec![5, 1, 2, 3, 4, 0]);
+        assert_eq!(linked_list.iter().rev().copied().collect::<Vec<_>>(), vec![0, 4, 3, 2, 1, 5]);
+
+        // Apart
+        linked_list.swap(4, 1);
+        linked_list.swap(3, 3);
+        assert_eq!(linked_list.iter().copied().collect::<Vec<_>>(), vec![5, 4, 2, 3, 1, 0]);
+        assert_eq!(linked_list.iter().rev().copied().collect::<Vec<_>>(), vec![0, 1, 3, 2, 4, 5]);
+        assert_eq!(linked_list.len(), 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_swap_out_of_bounds() {
+        let mut linked_list = (0..5).collect::<LinkedList<_>>();
+        linked_list.swap(0, 5);
+    }
+
+    #[test]
+    fn test_cursor_swap_with_next() {
+        let mut linked_list = LinkedList::from(vec![1337, 42, 666]);
+
+        let mut cursor = linked_list.cursor_mut();
+        cursor.swap_with_next();
+        assert_eq!(cursor.next_data(), Some(&42));
+
+        cursor.move_next();
+        cursor.swap_with_next();
+        assert_eq!(cursor.next_data(), Some(&666));
+
+        // Nothing to swap with at the end
+        cursor.move_next();
+        cursor.swap_with_next();
+        assert_eq!(cursor.next_data(), Some(&1337));
+
+        assert_eq!(linked_list.iter().collect::<Vec<_>>(), vec![&42, &666, &1337]);
+        assert_eq!(linked_list.iter().rev().collect::<Vec<_>>(), vec![&1337, &666, &42]);
+    }
+
+    #[test]
+    fn test_range() {
+        let linked_list = (0..10).collect::<LinkedList<_>>();
+
+        assert_eq!(linked_list.range(2..5).copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+        assert_eq!(linked_list.range(7..).copied().collect::<Vec<_>>(), vec![7, 8, 9]);
+        assert_eq!(linked_list.range(..=1).copied().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(linked_list.range(..).len(), 10);
+        assert_eq!(linked_list.range(4..4).next(), None);
+
+        let mut range = linked_list.range(3..=6);
+        assert_eq!(range.len(), 4);
+        assert_eq!(range.next_back(), Some(&6));
+        assert_eq!(range.next(), Some(&3));
+        assert_eq!(range.next_back(), Some(&5));
+        assert_eq!(range.next(), Some(&4));
+        assert_eq!(range.len(), 0);
+        assert_eq!(range.next(), None);
+        assert_eq!(range.next_back(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_range_out_of_bounds() {
+        let linked_list = (0..10).collect::<LinkedList<_>>();
+        linked_list.range(5..11);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range for usize")]
+    fn test_range_end_overflow() {
+        let linked_list = (0..10).collect::<LinkedList<_>>();
+        linked_list.range(5..=usize::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range for usize")]
+    fn test_range_start_overflow() {
+        let linked_list = (0..10).collect::<LinkedList<_>>();
+        linked_list.range((Bound::Excluded(usize::MAX), Bound::Unbounded));
+    }
+
+    #[test]
+    fn test_range_mut() {
+        let mut linked_list = (0..6).collect::<LinkedList<_>>();
+        linked_list.range_mut(1..3).for_each(|x| *x *= 10);
+        linked_list.range_mut(4..).rev().for_each(|x| *x += 100);
+
+        assert_eq!(linked_list.iter().copied().collect::<Vec<_>>(), vec![0, 10, 20, 3, 104, 105]);
+    }
+
+    #[test]
+    fn test_drain() {
+        let mut linked_list = (0..10).collect::<LinkedList<_>>();
+
+        let mut drain = linked_list.drain(2..6);
+        assert_eq!(drain.len(), 4);
+        assert_eq!(drain.next(), Some(2));
+        assert_eq!(drain.next_back(), Some(5));
+        assert_eq!(drain.collect::<Vec<_>>(), vec![3, 4]);
+
+        assert_eq!(linked_list.len(), 6);
+        assert_eq!(linked_list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 6, 7, 8, 9]);
+        assert_eq!(linked_list.iter().rev().copied().collect::<Vec<_>>(), vec![9, 8, 7, 6, 1, 0]);
+
+        // Drain both ends
+        assert_eq!(linked_list.drain(..2).collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(linked_list.drain(3..).collect::<Vec<_>>(), vec![9]);
+        assert_eq!(linked_list.iter().copied().collect::<Vec<_>>(), vec![6, 7, 8]);
+        assert_eq!(linked_list.pop_back(), Some(8));
+        assert_eq!(linked_list.pop_front(), Some(6));
+    }
+
+    #[test]
+    fn test_drain_dropped_early() {
+        let mut linked_list = LinkedList::from(vec![
+            String::from("foo"),
+            String::from("bar"),
+            String::from("baz"),
+            String::from("qux"),
+        ]);
+
+        let mut drain = linked_list.drain(1..3);
+        assert_eq!(drain.next().unwrap().as_str(), "bar");
+        drop(drain);
 
-        linked_list.push_front(1337);
-        assert_eq!(linked_list.len(), 1);
-        linked_list.push_front(42);
         assert_eq!(linked_list.len(), 2);
+        assert_eq!(linked_list.iter().collect::<Vec<_>>(), vec!["foo", "qux"]);
+
+        linked_list.drain(..);
+        assert!(linked_list.is_empty());
+        assert_eq!(linked_list.iter().count(), 0);
     }
 
     #[test]
-    fn test_push_back() {
+    fn test_macro() {
+        let linked_list: LinkedList<u32> = crate::linkedlist!();
+        assert!(linked_list.is_empty());
+
+        let linked_list = crate::linkedlist![1337, 42, 666];
+        assert_eq!(linked_list.iter().collect::<Vec<_>>(), vec![&1337, &42, &666]);
+    }
+
+    #[test]
+    fn test_cursor_move() {
         let mut linked_list = LinkedList::new();
 
         linked_list.push_back(1337);
-        assert_eq!(linked_list.len(), 1);
         linked_list.push_back(42);
-        assert_eq!(linked_list.len(), 2);
+        linked_list.push_back(666);
+
+        let mut cursor = linked_list.cursor_mut();
+        assert_eq!(cursor.next_data(), Some(&1337));
+        cursor.move_next();
+        assert_eq!(cursor.next_data(), Some(&42));
+        cursor.move_next();
+        assert_eq!(cursor.next_data(), Some(&666));
+        cursor.move_next();
+        assert_eq!(cursor.next_data(), None);
+
+        // Aaaaand back again
+        assert_eq!(cursor.previous_data(), Some(&666));
+        cursor.move_previous();
+        assert_eq!(cursor.previous_data(), Some(&42));
+        cursor.move_previous();
+        assert_eq!(cursor.previous_data(), Some(&1337));
+        cursor.move_previous();
+        assert_eq!(cursor.previous_data(), None);
     }
 
     #[test]
-    fn test_partial_eq() {
-        let mut linked_list = LinkedList::new();
-        linked_list.push_front(1337);
-        linked_list.push_front(42);
+    fn test_cursor_index() {
+        let mut linked_list = LinkedList::from(vec![1337, 42, 666]);
+
+        let mut cursor = linked_list.cursor_mut();
+        assert_eq!(cursor.index(), Some(0));
+        assert!(cursor.at_front());
+        assert!(!cursor.at_back());
 
-        let mut other_linked_list = LinkedList::new();
-        other_linked_list.push_front(1337);
-        other_linked_list.push_front(42);
+        // The ghost has no index
+        cursor.move_previous();
+        assert_eq!(cursor.index(), None);
+        assert!(!cursor.at_front());
+        assert!(!cursor.at_back());
 
-        assert_eq!(linked_list, other_linked_list);
-        other_linked_list.push_front(666);
+        cursor.move_next();
+        cursor.move_next();
+        cursor.push(7);
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.next_data(), Some(&42));
 
-        assert_ne!(linked_list, other_linked_list);
+        (0 .. 2).for_each(|_| cursor.move_next());
+        assert_eq!(cursor.index(), Some(4));
+        assert!(cursor.at_back());
+
+        assert_eq!(linked_list.find(|x| *x == 666).unwrap().index(), Some(3));
+        assert_eq!(linked_list.rfind(|x| *x == 1337).unwrap().index(), Some(0));
     }
 
     #[test]
-    fn test_display() {
-        let mut linked_list = LinkedList::new();
-        assert_eq!(format!("{linked_list}"), "");
+    fn test_cursor_seek() {
+        let mut linked_list = (0 .. 10).collect::<LinkedList<_>>();
 
-        linked_list.push_front(1337);
-        linked_list.push_front(42);
-        linked_list.push_front(666);
+        let mut cursor = linked_list.cursor_mut();
+        for index in [7, 2, 10, 9, 0, 5, 5] {
+            cursor.seek_to(index);
+            assert_eq!(cursor.index(), Some(index));
+            assert_eq!(cursor.next_data(), (index < 10).then_some(&index));
+            assert_eq!(cursor.previous_data(), index.checked_sub(1).as_ref());
+        }
 
-        assert_eq!(format!("{linked_list}"), "666, 42, 1337, ");
+        cursor.move_by(3);
+        assert_eq!(cursor.next_data(), Some(&8));
+        cursor.move_by(-6);
+        assert_eq!(cursor.next_data(), Some(&2));
+
+        // Wraps around through the ghost
+        cursor.move_by(-3);
+        assert!(cursor.is_ghost());
+        cursor.move_by(1);
+        assert!(cursor.at_front());
+        cursor.move_by(-2);
+        assert!(cursor.at_back());
+
+        // The 12 places are the 11 gaps and the ghost
+        cursor.move_by(-12 * 1337);
+        assert_eq!(cursor.index(), Some(10));
+        cursor.move_by(isize::MAX);
+        assert_eq!(cursor.index(), Some(5));
+        cursor.move_by(isize::MIN);
+        assert_eq!(cursor.index(), Some(9));
     }
 
     #[test]
-    fn test_iter() {
-        let mut linked_list = LinkedList::new();
-        linked_list.push_front(1337);
-        linked_list.push_front(42);
-        linked_list.push_back(666);
+    fn test_cursor_ghost() {
+        let mut linked_list = LinkedList::from(vec![1337, 42, 666]);
 
-        let mut linked_list_iter = linked_list.iter();
-        assert_eq!(linked_list_iter.next(), Some(&42));
-        assert_eq!(linked_list_iter.next(), Some(&1337));
-        assert_eq!(linked_list_iter.next(), Some(&666));
-        assert_eq!(linked_list_iter.next(), None);
-    }
+        let mut cursor = linked_list.cursor_mut();
+        cursor.seek_to(3);
+        cursor.move_next();
+        assert!(cursor.is_ghost());
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.next_data(), Some(&1337));
+        assert_eq!(cursor.previous_data(), Some(&666));
+        assert_eq!(cursor.iter_before().count(), 3);
+        assert_eq!(cursor.iter_after().count(), 3);
 
-    #[test]
-    fn test_iter_double_ended() {
-        let mut linked_list = LinkedList::new();
-        linked_list.push_front(1337);
-        linked_list.push_front(42);
-        linked_list.push_front(666);
+        // Pushing appends to the back, removing pops the front
+        cursor.push(7);
+        assert_eq!(cursor.previous_data(), Some(&7));
+        assert_eq!(cursor.remove_next(), Some(1337));
+        assert_eq!(cursor.next_data(), Some(&42));
+        assert!(cursor.is_ghost());
 
-        let mut linked_list_iter = linked_list.iter();
-        assert_eq!(linked_list_iter.next_back(), Some(&1337));
-        assert_eq!(linked_list_iter.next(), Some(&666));
-        assert_eq!(linked_list_iter.next_back(), Some(&42));
-        assert_eq!(linked_list_iter.next(), Some(&42));
-        assert_eq!(linked_list_iter.next_back(), Some(&666));
-        assert_eq!(linked_list_iter.next(), Some(&1337));
-        assert_eq!(linked_list_iter.next_back(), None);
-        assert_eq!(linked_list_iter.next(), None);
-    }
+        cursor.seek_to(1);
+        assert_eq!(cursor.index(), Some(1));
+        assert_eq!(cursor.next_data(), Some(&666));
+        cursor.move_previous();
+        cursor.move_previous();
+        assert!(cursor.is_ghost());
+        cursor.move_previous();
+        assert_eq!(cursor.index(), Some(3));
+        assert_eq!(linked_list.iter().collect::<Vec<_>>(), vec![&42, &666, &7]);
 
-    #[test]
-    fn test_into_iter_double_ended() {
-        let mut linked_list = LinkedList::new();
-        linked_list.push_front(1337);
-        linked_list.push_front(42);
-        linked_list.push_front(666);
+        // An empty list is just the front, which is also the back, and the ghost
+        let mut linked_list = LinkedList::from(vec![1337]);
+        let mut cursor = linked_list.cursor_mut();
+        cursor.move_previous();
+        assert_eq!(cursor.remove_next(), Some(1337));
+        assert_eq!(cursor.previous_data(), None);
+        cursor.move_next();
+        assert!(cursor.at_front() && cursor.at_back());
+        cursor.move_next();
+        assert!(cursor.is_ghost());
+        cursor.push(42);
+        assert_eq!(cursor.next_data(), Some(&42));
+        assert_eq!(linked_list.iter().collect::<Vec<_>>(), vec![&42]);
 
-        let mut linked_list_iter = linked_list.into_iter();
-        assert_eq!(linked_list_iter.next_back(), Some(1337));
-        assert_eq!(linked_list_iter.next(), Some(666));
-        assert_eq!(linked_list_iter.next_back(), Some(42));
-        assert_eq!(linked_list_iter.next(), None);
-        assert_eq!(linked_list_iter.next_back(), None);
+        // Everything is before the ghost as well as after it
+        let mut linked_list = (0 .. 5).collect::<LinkedList<_>>();
+        let mut cursor = linked_list.cursor_mut();
+        cursor.move_previous();
+        assert_eq!(cursor.drain_before().len(), 5);
+        assert_eq!(cursor.next_data(), None);
+        assert_eq!(cursor.previous_data(), None);
     }
 
     #[test]
-    fn test_from_iter() {
-        let vec = vec![1337, 42, 666];
-
-        let mut linked_list_iter = LinkedList::from_iter(vec.clone().into_iter())
-            .into_iter();
-        assert_eq!(linked_list_iter.next(), Some(1337));
-        assert_eq!(linked_list_iter.next(), Some(42));
-        assert_eq!(linked_list_iter.next(), Some(666));
-        assert_eq!(linked_list_iter.next(), None);
+    #[should_panic(expected = "seek index 4 out of range for list of length 3")]
+    fn test_cursor_seek_out_of_bounds() {
+        let mut linked_list = LinkedList::from(vec![1337, 42, 666]);
+        linked_list.cursor_mut().seek_to(4);
     }
 
     #[test]
-    fn test_from_vec() {
-        let vec = vec![1337, 42, 666];
+    fn test_cursor_pop_around() {
+        let mut linked_list = LinkedList::from(vec![1337, 42, 666]);
 
-        let mut linked_list_iter = LinkedList::from(vec)
-            .into_iter();
-        assert_eq!(linked_list_iter.next(), Some(1337));
-        assert_eq!(linked_list_iter.next(), Some(42));
-        assert_eq!(linked_list_iter.next(), Some(666));
-        assert_eq!(linked_list_iter.next(), None);
-    }
+        // Popping the element right after the cursor
+        let mut cursor = linked_list.cursor_mut();
+        assert_eq!(cursor.pop_front(), Some(1337));
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.next_data(), Some(&42));
 
-    #[test]
-    fn test_into_iter() {
-        let mut linked_list = LinkedList::new();
-        linked_list.push_front(1337);
-        linked_list.push_front(42);
-        linked_list.push_back(666);
+        // And right before it
+        cursor.seek_to(2);
+        assert_eq!(cursor.pop_back(), Some(666));
+        assert_eq!(cursor.index(), Some(1));
+        assert!(cursor.at_back());
+        assert_eq!(cursor.previous_data(), Some(&42));
 
-        let mut linked_list_iter = linked_list.into_iter();
-        assert_eq!(linked_list_iter.size_hint(), (3, Some(3)));
-        assert_eq!(linked_list_iter.next(), Some(42));
-        assert_eq!(linked_list_iter.next(), Some(1337));
-        assert_eq!(linked_list_iter.next(), Some(666));
-        assert_eq!(linked_list_iter.next(), None);
+        assert_eq!(cursor.pop_front(), Some(42));
+        assert_eq!(cursor.index(), Some(0));
+        assert!(cursor.at_front() && cursor.at_back());
+        cursor.push(7);
+
+        assert_eq!(linked_list.iter().collect::<Vec<_>>(), vec![&7]);
     }
 
     #[test]
-    fn test_drop() {
-        let mut linked_list = LinkedList::new();
-        linked_list.push_front(1337);
-        linked_list.push_front(42);
-        linked_list.push_back(666);
+    fn test_cursor_as_list() {
+        let mut linked_list = LinkedList::from(vec![1337, 42, 666, 7]);
 
-        drop(linked_list);
+        let mut cursor = linked_list.cursor_mut();
+        cursor.seek_to(2);
+        assert_eq!(cursor.as_list().len(), 4);
+        assert_eq!(cursor.as_list()[3], 7);
+
+        assert_eq!(cursor.iter_after().collect::<Vec<_>>(), vec![&666, &7]);
+        assert_eq!(cursor.iter_before().collect::<Vec<_>>(), vec![&1337, &42]);
+        assert_eq!(cursor.iter_before().rev().collect::<Vec<_>>(), vec![&42, &1337]);
+        assert_eq!(cursor.iter_after().len(), 2);
+
+        cursor.seek_to(4);
+        assert_eq!(cursor.iter_after().next(), None);
+        assert_eq!(cursor.iter_before().len(), 4);
     }
 
     #[test]
-    fn test_get() {
-        let mut linked_list = LinkedList::new();
-        linked_list.push_front(1337);
-        linked_list.push_front(42);
-        linked_list.push_back(666);
+    fn test_cursor_remove_while() {
+        let mut linked_list = "foo  bar baz".chars().collect::<LinkedList<_>>();
 
-        assert_eq!(linked_list.get(0), Some(&42));
-        assert_eq!(linked_list.get(1), Some(&1337));
-        assert_eq!(linked_list.get(2), Some(&666));
-        assert_eq!(linked_list.get(3), None);
+        // Scan the words off the front, skipping the spaces in between
+        let mut words = Vec::new();
+        let mut cursor = linked_list.cursor_mut();
+        while !cursor.at_back() {
+            cursor.remove_while(|c| *c == ' ');
+            words.push(cursor.remove_while(|c| *c != ' ').into_iter().collect::<String>());
+        }
+
+        assert_eq!(words, vec!["foo", "bar", "baz"]);
+        assert!(linked_list.is_empty());
+
+        let mut linked_list = LinkedList::from(vec![1, 2, 3, 4]);
+        let mut cursor = linked_list.cursor_mut();
+        cursor.move_next();
+        assert!(cursor.remove_while(|x| *x > 2).is_empty());
+        assert_eq!(cursor.remove_while(|x| *x < 4).iter().collect::<Vec<_>>(), vec![&2, &3]);
+        assert_eq!(cursor.next_data(), Some(&4));
+        assert_eq!(linked_list.iter().rev().collect::<Vec<_>>(), vec![&4, &1]);
     }
 
     #[test]
-    fn test_push_front_pop_front() {
-        let mut linked_list = LinkedList::new();
-        linked_list.push_front(String::from("foo"));
-        linked_list.push_front(String::from("bar"));
-        linked_list.push_front(String::from("baz"));
+    fn test_cursor_drain() {
+        let mut linked_list = LinkedList::from(vec![1337, 42, 666, 7, 0]);
 
-        assert_eq!(linked_list.len(), 3);
-        assert_eq!(linked_list.pop_front().unwrap().as_str(), "baz");
-        assert_eq!(linked_list.len(), 2);
-        assert_eq!(linked_list.pop_front().unwrap().as_str(), "bar");
+        let mut cursor = linked_list.cursor_mut();
+        cursor.seek_to(4);
+        assert_eq!(cursor.drain_after().iter().collect::<Vec<_>>(), vec![&0]);
+        assert!(cursor.drain_after().is_empty());
+        assert!(cursor.at_back());
+
+        cursor.seek_to(1);
+        let before = cursor.drain_before();
+        assert_eq!(before.iter().rev().collect::<Vec<_>>(), vec![&1337]);
+        assert_eq!(cursor.index(), Some(0));
+        assert!(cursor.drain_before().is_empty());
+
+        cursor.push(1);
+        assert_eq!(cursor.as_list().iter().collect::<Vec<_>>(), vec![&1, &42, &666, &7]);
+
+        let after = cursor.drain_after();
+        assert_eq!(after.iter().rev().collect::<Vec<_>>(), vec![&7, &666, &42]);
+        assert_eq!(linked_list.iter().rev().collect::<Vec<_>>(), vec![&1]);
         assert_eq!(linked_list.len(), 1);
-        assert_eq!(linked_list.pop_front().unwrap().as_str(), "foo");
-        assert_eq!(linked_list.len(), 0);
-        assert_eq!(linked_list.pop_front(), None);
-
-        assert_eq!(linked_list.iter().count(), 0);
     }
 
     #[test]
-    fn test_push_back_pop_back() {
-        let mut linked_list = LinkedList::new();
-        linked_list.push_back(String::from("foo"));
-        linked_list.push_back(String::from("bar"));
-        linked_list.push_back(String::from("baz"));
+    fn test_iter_peek() {
+        let linked_list = LinkedList::from(vec![1337, 42, 666]);
 
-        assert_eq!(linked_list.pop_back().unwrap().as_str(), "baz");
-        assert_eq!(linked_list.pop_back().unwrap().as_str(), "bar");
-        assert_eq!(linked_list.pop_back().unwrap().as_str(), "foo");
-        assert_eq!(linked_list.pop_back(), None);
+        let mut linked_list_iter = linked_list.iter();
+        assert_eq!(linked_list_iter.peek_next(), Some(&1337));
+        assert_eq!(linked_list_iter.peek_back(), Some(&666));
+        assert_eq!(linked_list_iter.next(), Some(&1337));
+        assert_eq!(linked_list_iter.peek_next(), Some(&42));
 
-        assert_eq!(linked_list.iter().count(), 0);
+        assert_eq!(linked_list_iter.remaining_as_list_view().iter().collect::<Vec<_>>(), vec![&42, &666]);
+        assert_eq!(linked_list_iter.next_back(), Some(&666));
+        assert_eq!(linked_list_iter.peek_back(), Some(&42));
+        assert_eq!(linked_list_iter.peek_next(), Some(&42));
+        assert_eq!(linked_list_iter.remaining_as_list_view().last(), Some(&42));
+        assert_eq!(linked_list_iter.next_back(), Some(&42));
+        assert_eq!(linked_list_iter.remaining_as_list_view().len(), 0);
+        assert_eq!(linked_list_iter.remaining_as_list_view().first(), None);
+        assert_eq!(linked_list_iter.peek_next(), None);
+        assert_eq!(linked_list_iter.peek_back(), None);
+
+        assert_eq!(LinkedList::<u32>::new().iter().peek_next(), None);
     }
 
     #[test]
-    fn test_push_front_pop_back() {
-        let mut linked_list = LinkedList::new();
+    fn test_iter_windows() {
+        let linked_list = LinkedList::from(vec![1337, 42, 666, 7]);
 
-        linked_list.push_front(42);
-        linked_list.push_front(1337);
+        let windows = linked_list.iter().windows(2)
+            .map(|window| window.iter().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(windows, vec![vec![&1337, &42], vec![&42, &666], vec![&666, &7]]);
 
-        assert_eq!(linked_list.pop_back(), Some(42));
-        assert!(linked_list.head.is_some());
-        assert!(linked_list.foot.is_some());
-        assert_eq!(linked_list.head, linked_list.foot);
+        let mut linked_list_iter = linked_list.iter();
+        linked_list_iter.next();
+        let sums = linked_list_iter.windows(3).map(|window| window.iter().sum::<i32>()).collect::<Vec<_>>();
+        assert_eq!(sums, vec![715]);
 
-        assert_eq!(linked_list.pop_back(), Some(1337));
-        assert!(linked_list.head.is_none());
-        assert!(linked_list.foot.is_none());
-    }
+        assert_eq!(linked_list.iter().windows(4).count(), 1);
+        assert_eq!(linked_list.iter().windows(5).count(), 0);
+        assert_eq!(LinkedList::<u32>::new().iter().windows(1).count(), 0);
 
-    #[test]
-    fn test_push_back_pop_front() {
-        let mut linked_list = LinkedList::new();
-        linked_list.push_back(String::from("foo"));
-        linked_list.push_back(String::from("bar"));
-        linked_list.push_back(String::from("baz"));
+        // Only over what is left after taking from the back
+        let mut linked_list_iter = linked_list.iter();
+        linked_list_iter.next_back();
+        let windows = linked_list_iter.windows(2)
+            .map(|window| window.iter().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(windows, vec![vec![&1337, &42], vec![&42, &666]]);
 
-        assert_eq!(linked_list.pop_front().unwrap().as_str(), "foo");
-        assert_eq!(linked_list.pop_front().unwrap().as_str(), "bar");
-        assert_eq!(linked_list.pop_front().unwrap().as_str(), "baz");
-        assert_eq!(linked_list.pop_front(), None);
+        let mut linked_list_iter = linked_list.iter();
+        linked_list_iter.next_back();
+        assert_eq!(linked_list_iter.windows(4).count(), 0);
+    }
 
-        assert_eq!(linked_list.iter().count(), 0);
+    #[test]
+    #[should_panic]
+    fn test_iter_windows_zero() {
+        LinkedList::from(vec![1337]).iter().windows(0);
     }
 
     #[test]
-    fn test_pop_none() {
-        let mut linked_list = LinkedList::<u32>::new();
+    fn test_iter_chunks() {
+        let linked_list = LinkedList::from(vec![1337, 42, 666, 7, 0]);
 
-        assert_eq!(linked_list.pop_front(), None);
-        assert_eq!(linked_list.pop_back(), None);
+        let chunks = linked_list.iter().chunks(2)
+            .map(|chunk| chunk.iter().rev().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(chunks, vec![vec![&42, &1337], vec![&7, &666], vec![&0]]);
 
-        assert_eq!(linked_list.iter().count(), 0);
+        assert_eq!(linked_list.iter().chunks(5).map(|chunk| chunk.len()).collect::<Vec<_>>(), vec![5]);
+        assert_eq!(linked_list.iter().chunks(7).map(|chunk| chunk.len()).collect::<Vec<_>>(), vec![5]);
+        assert_eq!(LinkedList::<u32>::new().iter().chunks(3).count(), 0);
+
+        // Only over what is left after taking from the back
+        let mut linked_list_iter = linked_list.iter();
+        linked_list_iter.next_back();
+        linked_list_iter.next_back();
+        let chunks = linked_list_iter.chunks(2)
+            .map(|chunk| chunk.iter().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(chunks, vec![vec![&1337, &42], vec![&666]]);
     }
 
     #[test]
-    fn test_push_pop_alternate() {
-        let mut linked_list = LinkedList::new();
-        linked_list.push_back(String::from("foo"));
-        linked_list.push_front(String::from("bar"));
+    fn test_cursor_slice() {
+        let mut linked_list = LinkedList::from(vec![1337, 42, 666, 7, 0]);
 
-        assert_eq!(linked_list.pop_back().unwrap().as_str(), "foo");
-        assert_eq!(linked_list.pop_front().unwrap().as_str(), "bar");
-        assert_eq!(linked_list.pop_front(), None);
+        let mut cursor = linked_list.cursor_mut();
+        cursor.seek_to(2);
+        assert_eq!(cursor.slice_to(4).iter().collect::<Vec<_>>(), vec![&666, &7]);
+        assert_eq!(cursor.slice_to(5).last(), Some(&0));
+        assert_eq!(cursor.slice_to(0).iter().collect::<Vec<_>>(), vec![&1337, &42]);
+        assert!(cursor.slice_to(2).is_empty());
 
-        assert_eq!(linked_list.iter().count(), 0);
+        cursor.seek_to(5);
+        assert_eq!(cursor.slice_to(3).iter().collect::<Vec<_>>(), vec![&7, &0]);
+
+        cursor.seek_to(1);
+        cursor.into_slice_mut(5).sort();
+        assert_eq!(linked_list.iter().collect::<Vec<_>>(), vec![&1337, &0, &7, &42, &666]);
+
+        let mut cursor = linked_list.cursor_mut();
+        cursor.seek_to(3);
+        cursor.into_slice_mut(0).reverse();
+        assert_eq!(linked_list.iter().rev().collect::<Vec<_>>(), vec![&666, &42, &1337, &0, &7]);
     }
 
     #[test]
-    fn test_push_pop_alternate_2() {
-        let mut linked_list = LinkedList::new();
-        linked_list.push_front(String::from("foo"));
-        linked_list.push_back(String::from("bar"));
+    fn test_concat() {
+        let lists = vec![LinkedList::from(vec![1337, 42]), LinkedList::new(), LinkedList::from(vec![666])];
 
-        assert_eq!(linked_list.pop_front().unwrap().as_str(), "foo");
-        assert_eq!(linked_list.pop_back().unwrap().as_str(), "bar");
-        assert_eq!(linked_list.pop_back(), None);
+        let linked_list = LinkedList::concat(lists);
+        assert_eq!(linked_list.len(), 3);
+        assert_eq!(linked_list.iter().rev().collect::<Vec<_>>(), vec![&666, &42, &1337]);
 
-        assert_eq!(linked_list.iter().count(), 0);
+        assert!(LinkedList::<u32>::concat(Vec::new()).is_empty());
     }
 
     #[test]
-    fn test_push_insert() {
-        let mut linked_list = LinkedList::new();
-        linked_list.push_back(String::from("foo"));
-        linked_list.push_back(String::from("bar"));
-        linked_list.insert(1, String::from("baz"));
-
-
-        let mut linked_list_iter = linked_list.iter();
-        assert_eq!(linked_list_iter.next().unwrap().as_str(), "foo");
-        assert_eq!(linked_list_iter.next().unwrap().as_str(), "bar");
-        assert_eq!(linked_list_iter.next().unwrap().as_str(), "baz");
-        assert_eq!(linked_list_iter.next(), None);
+    fn test_partition_in_place() {
+        let linked_list = (0 .. 10).collect::<LinkedList<_>>();
+
+        let (even, odd) = linked_list.partition_in_place(|x| x % 2 == 0);
+        assert_eq!(even.iter().collect::<Vec<_>>(), vec![&0, &2, &4, &6, &8]);
+        assert_eq!(odd.iter().rev().collect::<Vec<_>>(), vec![&9, &7, &5, &3, &1]);
+        assert_eq!(even.len(), 5);
+        assert_eq!(odd.len(), 5);
+
+        let (all, none) = LinkedList::from(vec![1337]).partition_in_place(|_| true);
+        assert_eq!(all.len(), 1);
+        assert!(none.is_empty());
     }
 
     #[test]
-    fn test_cursor_move() {
-        let mut linked_list = LinkedList::new();
+    fn test_split_when() {
+        let linked_list = LinkedList::from(vec![1, 2, 3, 7, 8, 42, 1337, 1338]);
 
-        linked_list.push_back(1337);
-        linked_list.push_back(42);
-        linked_list.push_back(666);
+        let pieces = linked_list.split_when(|a, b| b - a > 1)
+            .map(|piece| piece.iter().rev().copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(pieces, vec![vec![3, 2, 1], vec![8, 7], vec![42], vec![1338, 1337]]);
 
-        let mut cursor = linked_list.cursor_mut();
-        assert_eq!(cursor.next_data(), Some(&1337));
-        cursor.move_next();
-        assert_eq!(cursor.next_data(), Some(&42));
-        cursor.move_next();
-        assert_eq!(cursor.next_data(), Some(&666));
-        cursor.move_next();
-        assert_eq!(cursor.next_data(), None);
+        assert_eq!(LinkedList::from(vec![1, 2]).split_when(|_, _| false).count(), 1);
+        assert_eq!(LinkedList::<u32>::new().split_when(|_, _| true).count(), 0);
+    }
 
-        // Aaaaand back again
-        assert_eq!(cursor.previous_data(), Some(&666));
-        cursor.move_previous();
-        assert_eq!(cursor.previous_data(), Some(&42));
-        cursor.move_previous();
-        assert_eq!(cursor.previous_data(), Some(&1337));
-        cursor.move_previous();
-        assert_eq!(cursor.previous_data(), None);
+    #[test]
+    fn test_group_by_key() {
+        let linked_list = LinkedList::from(vec!["foo", "bar", "fizz", "baz", "buzz", "hej"]);
+
+        let groups = linked_list.group_by_key(|word| word.len());
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].0, 3);
+        assert_eq!(groups[0].1.iter().collect::<Vec<_>>(), vec![&"foo", &"bar", &"baz", &"hej"]);
+        assert_eq!(groups[1].0, 4);
+        assert_eq!(groups[1].1.iter().rev().collect::<Vec<_>>(), vec![&"buzz", &"fizz"]);
+        assert_eq!(groups[1].1.len(), 2);
     }
 }
@@ -0,0 +1,350 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::linked_list::{IterMut, LinkedList, Range};
+use crate::node::NodeLink;
+use crate::{data_unsafe, next, next_unsafe, previous, previous_unsafe};
+
+/// Splits the `length` nodes from `front` to `back`, so `index` of them go left.
+///
+/// Returns the last node on the left and the first on the right, walking from the nearer end.
+pub(crate) fn split_nodes<T>(front: NodeLink<T>, back: NodeLink<T>, length: usize, index: usize) -> (NodeLink<T>, NodeLink<T>) {
+    if index == 0 {
+        return (None, front);
+    }
+
+    if index == length {
+        return (back, None);
+    }
+
+    unsafe {
+        if index <= length / 2 {
+            let mut left_back = front.unwrap();
+            for _ in 1 .. index {
+                left_back = next!(left_back).unwrap();
+            }
+
+            (Some(left_back), next!(left_back))
+        }
+        else {
+            let mut right_front = back.unwrap();
+            for _ in index + 1 .. length {
+                right_front = previous!(right_front).unwrap();
+            }
+
+            (previous!(right_front), Some(right_front))
+        }
+    }
+}
+
+/// A view of a run of nodes in a `LinkedList`, like a `&[T]` is of a run of elements.
+///
+/// It is just the first and last node and the length, so it is cheap to copy around.
+/// Views are made with `LinkedList::slice` or from a cursor and an index with `Cursor::slice_to`,
+/// not from two cursors, as a cursor borrows the whole list mutably.
+pub struct ListSlice<'a, T> {
+    front: NodeLink<T>,
+    back: NodeLink<T>,
+    length: usize,
+    _phantom: PhantomData<&'a T>,
+}
+
+impl<'a, T> ListSlice<'a, T> {
+    /// Views the `length` nodes from `front` to `back`.
+    ///
+    /// # Safety
+    /// `back` must be `length - 1` nodes after `front`, in a list borrowed for `'a`.
+    pub(crate) unsafe fn from_nodes(front: NodeLink<T>, back: NodeLink<T>, length: usize) -> Self {
+        match length {
+            0 => Self { front: None, back: None, length, _phantom: PhantomData },
+            _ => Self { front, back, length, _phantom: PhantomData },
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    pub fn first(&self) -> Option<&'a T> {
+        self.front.map(|node| data_unsafe!(node))
+    }
+
+    pub fn last(&self) -> Option<&'a T> {
+        self.back.map(|node| data_unsafe!(node))
+    }
+
+    /// Gets the element at `index` in the slice, walking from its nearer end
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        if index >= self.length {
+            return None;
+        }
+
+        let mut current = self.front;
+        if index < self.length / 2 {
+            (0 .. index).for_each(|_| current = current.and_then(|node| next_unsafe!(node)));
+        }
+        else {
+            current = self.back;
+            (index + 1 .. self.length).for_each(|_| current = current.and_then(|node| previous_unsafe!(node)));
+        }
+
+        current.map(|node| data_unsafe!(node))
+    }
+
+    /// Splits the slice in two, with the first `mid` elements on the left.
+    ///
+    /// # Panics
+    /// If `mid` is greater than the length of the slice.
+    pub fn split_at(&self, mid: usize) -> (ListSlice<'a, T>, ListSlice<'a, T>) {
+        assert!(mid <= self.length, "split_at: mid ({mid}) > len ({})", self.length);
+
+        let (left_back, right_front) = split_nodes(self.front, self.back, self.length, mid);
+
+        unsafe {
+            (
+                ListSlice::from_nodes(self.front, left_back, mid),
+                ListSlice::from_nodes(right_front, self.back, self.length - mid),
+            )
+        }
+    }
+
+    pub fn iter(&self) -> Range<'a, T> {
+        unsafe { Range::from_nodes(self.front, self.back, self.length) }
+    }
+}
+
+impl<'a, T> Clone for ListSlice<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for ListSlice<'a, T> {}
+
+impl<'a, T> IntoIterator for ListSlice<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Range<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: PartialEq> PartialEq for ListSlice<'a, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.length == other.length && self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for ListSlice<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// A mutable view of a run of nodes in a `LinkedList`.
+///
+/// Sorting and reversing relink the nodes of the run only, the rest of the list stays as it is.
+/// Views are made with `LinkedList::slice_mut` or `Cursor::into_slice_mut`.
+pub struct ListSliceMut<'a, T> {
+    list: &'a mut LinkedList<T>,
+    front: NodeLink<T>,
+    back: NodeLink<T>,
+    length: usize,
+}
+
+impl<'a, T> ListSliceMut<'a, T> {
+    /// Views the `length` nodes of `list` from `front` to `back`.
+    ///
+    /// # Safety
+    /// `back` must be `length - 1` nodes after `front`, in `list`.
+    pub(crate) unsafe fn from_nodes(list: &'a mut LinkedList<T>, front: NodeLink<T>, back: NodeLink<T>, length: usize) -> Self {
+        match length {
+            0 => Self { list, front: None, back: None, length },
+            _ => Self { list, front, back, length },
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Gets a shared view of the same run, for `get`, `first`, `split_at` and such
+    pub fn as_slice(&self) -> ListSlice<'_, T> {
+        unsafe { ListSlice::from_nodes(self.front, self.back, self.length) }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        unsafe { IterMut::from_nodes(self.front, self.back, self.length) }
+    }
+
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp)
+    }
+
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        self.sort_by(|a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Stable merge sort of the run, only relinking its nodes.
+    ///
+    /// If `compare` panics the run is left in the list, in some order, with all its elements.
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: F) {
+        self.with_detached(|run| run.sort_by(compare));
+    }
+
+    /// Reverses the run by flipping the links of its nodes
+    pub fn reverse(&mut self) {
+        self.with_detached(|run| run.reverse());
+    }
+
+    /// Takes the run out of the list as a list of its own, for `f` to work on,
+    /// then links it back in where it was
+    fn with_detached<F: FnOnce(&mut LinkedList<T>)>(&mut self, f: F) {
+        let (Some(front), Some(back)) = (self.front, self.back) else {
+            return;
+        };
+
+        let (previous, next) = unsafe { (previous!(front), next!(back)) };
+        let run = unsafe { self.list.detach(front, back, self.length) };
+
+        let mut guard = AttachGuard { slice: self, run, previous, next };
+        f(&mut guard.run);
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for ListSliceMut<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+/// Links a detached run back into the list of its slice when dropped, also on panic
+struct AttachGuard<'s, 'a, T> {
+    slice: &'s mut ListSliceMut<'a, T>,
+    run: LinkedList<T>,
+    previous: NodeLink<T>,
+    next: NodeLink<T>,
+}
+
+impl<'s, 'a, T> Drop for AttachGuard<'s, 'a, T> {
+    fn drop(&mut self) {
+        let run = std::mem::take(&mut self.run);
+
+        // The run may have new ends after being relinked
+        self.slice.front = run.head_link();
+        self.slice.back = run.foot_link();
+
+        unsafe { self.slice.list.attach(self.previous, run, self.next) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::linked_list::LinkedList;
+
+    #[test]
+    fn test_slice() {
+        let linked_list = LinkedList::from(vec![1337, 42, 666, 7, 0]);
+
+        let slice = linked_list.slice(1 .. 4);
+        assert_eq!(slice.len(), 3);
+        assert_eq!(slice.first(), Some(&42));
+        assert_eq!(slice.last(), Some(&7));
+        assert_eq!(slice.get(0), Some(&42));
+        assert_eq!(slice.get(2), Some(&7));
+        assert_eq!(slice.get(3), None);
+        assert_eq!(slice.iter().rev().collect::<Vec<_>>(), vec![&7, &666, &42]);
+        assert_eq!(format!("{slice:?}"), "[42, 666, 7]");
+
+        let empty = linked_list.slice(2 .. 2);
+        assert!(empty.is_empty());
+        assert_eq!(empty.first(), None);
+        assert_eq!(empty.iter().next(), None);
+    }
+
+    #[test]
+    fn test_slice_split_at() {
+        let linked_list = LinkedList::from(vec![1337, 42, 666, 7, 0]);
+        let slice = linked_list.slice(..);
+
+        let (left, right) = slice.split_at(2);
+        assert_eq!(left.into_iter().collect::<Vec<_>>(), vec![&1337, &42]);
+        assert_eq!(right.into_iter().collect::<Vec<_>>(), vec![&666, &7, &0]);
+
+        let (left, right) = right.split_at(3);
+        assert_eq!(left, linked_list.slice(2 ..));
+        assert!(right.is_empty());
+
+        let (left, right) = slice.split_at(0);
+        assert!(left.is_empty());
+        assert_eq!(right.len(), 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_split_at_out_of_bounds() {
+        LinkedList::from(vec![1337, 42]).slice(..).split_at(3);
+    }
+
+    #[test]
+    fn test_slice_mut() {
+        let mut linked_list = LinkedList::from(vec![1337, 42, 666, 7, 0]);
+
+        let mut slice = linked_list.slice_mut(1 .. 4);
+        slice.iter_mut().for_each(|x| *x += 1);
+        assert_eq!(slice.as_slice().first(), Some(&43));
+
+        slice.sort();
+        assert_eq!(format!("{slice:?}"), "[8, 43, 667]");
+        assert_eq!(linked_list.iter().collect::<Vec<_>>(), vec![&1337, &8, &43, &667, &0]);
+
+        // Touching the ends of the list too
+        linked_list.slice_mut(..).reverse();
+        assert_eq!(linked_list.iter().rev().collect::<Vec<_>>(), vec![&1337, &8, &43, &667, &0]);
+
+        let mut slice = linked_list.slice_mut(.. 3);
+        slice.reverse();
+        slice.reverse();
+        slice.sort_by_key(|x| *x);
+        assert_eq!(linked_list.iter().collect::<Vec<_>>(), vec![&0, &43, &667, &8, &1337]);
+        assert_eq!(linked_list.len(), 5);
+
+        linked_list.slice_mut(5 ..).sort();
+        assert_eq!(linked_list.len(), 5);
+    }
+
+    #[test]
+    fn test_slice_mut_sort_panic() {
+        let mut linked_list = LinkedList::from(vec![5, 4, 3, 2, 1, 0]);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            linked_list.slice_mut(1 .. 5).sort_by(|a, b| {
+                assert_ne!(*a, 2);
+                a.cmp(b)
+            });
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(linked_list.len(), 6);
+        assert_eq!(linked_list.get(0), Some(&5));
+        assert_eq!(linked_list.get(5), Some(&0));
+
+        let mut elements = linked_list.iter().copied().collect::<Vec<_>>();
+        elements.sort();
+        assert_eq!(elements, vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(linked_list.iter().rev().count(), 6);
+    }
+}
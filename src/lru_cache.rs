@@ -0,0 +1,311 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::fmt;
+
+use crate::linked_list::{self, LinkedList};
+use crate::node::NodeLinkSome;
+
+/// A cache holding at most `capacity` entries, evicting the least recently used one.
+///
+/// The entries are kept in a `LinkedList` in recency order, with the most recently
+/// used in front, and a `HashMap` finds the node of a key. Every operation is O(1).
+pub struct LruCache<K, V> {
+    list: LinkedList<(K, V)>,
+    map: HashMap<K, NodeLinkSome<(K, V)>>,
+    capacity: usize,
+    on_evict: Option<Box<dyn FnMut(K, V)>>,
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            list: LinkedList::new(),
+            // The map grows with the entries, a large capacity is only a bound
+            map: HashMap::new(),
+            capacity,
+            on_evict: None,
+        }
+    }
+
+    /// Makes a cache that hands every entry it evicts to `on_evict`
+    pub fn with_eviction_callback<F: FnMut(K, V) + 'static>(capacity: usize, on_evict: F) -> Self {
+        Self {
+            on_evict: Some(Box::new(on_evict)),
+            ..Self::new(capacity)
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(key)
+    }
+
+    /// Gets the value of `key` and makes it the most recently used
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let node = *self.map.get(key)?;
+        self.promote(node);
+
+        Some(unsafe { &(*node.as_ptr()).data.1 })
+    }
+
+    /// Gets the value of `key` mutably and makes it the most recently used
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let node = *self.map.get(key)?;
+        self.promote(node);
+
+        Some(unsafe { &mut (*node.as_ptr()).data.1 })
+    }
+
+    /// Gets the value of `key` without touching its recency
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).map(|node| unsafe { &(*node.as_ptr()).data.1 })
+    }
+
+    /// Gets the least recently used entry, the next one to be evicted
+    pub fn peek_lru(&self) -> Option<(&K, &V)> {
+        self.iter().next_back()
+    }
+
+    /// Puts `value` in the cache as the most recently used, returning the old value of `key`.
+    ///
+    /// Evicts the least recently used entry if the cache is full.
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(&node) = self.map.get(&key) {
+            self.promote(node);
+
+            let old_value = unsafe { &mut (*node.as_ptr()).data.1 };
+            return Some(std::mem::replace(old_value, value));
+        }
+
+        let node = LinkedList::new_node_link((key.clone(), value));
+        self.list.link_node(None, node, self.list.head_link());
+        self.map.insert(key, node);
+
+        self.evict_to_capacity();
+
+        None
+    }
+
+    /// Removes `key` from the cache, without calling the eviction callback
+    pub fn pop<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.remove(key).map(|node| unsafe {
+            self.list.unlink_node(node);
+            Box::from_raw(node.as_ptr()).data.1
+        })
+    }
+
+    /// Removes the least recently used entry, without calling the eviction callback
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        let (key, value) = self.list.pop_back()?;
+        self.map.remove(&key);
+
+        Some((key, value))
+    }
+
+    /// Changes the capacity, evicting the least recently used entries that no longer fit
+    pub fn resize(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.evict_to_capacity();
+    }
+
+    /// Iterates over the entries, from the most to the least recently used
+    pub fn iter(&'_ self) -> Iter<'_, K, V> {
+        Iter { iter: self.list.iter() }
+    }
+
+    fn promote(&mut self, node: NodeLinkSome<(K, V)>) {
+        if self.list.head_link() != Some(node) {
+            unsafe { self.list.unlink_node(node) };
+            self.list.link_node(None, node, self.list.head_link());
+        }
+    }
+
+    fn evict_to_capacity(&mut self) {
+        while self.list.len() > self.capacity {
+            let (key, value) = self.pop_lru().unwrap();
+
+            if let Some(on_evict) = &mut self.on_evict {
+                on_evict(key, value);
+            }
+        }
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for LruCache<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.list.iter().map(|(key, value)| (key, value))).finish()
+    }
+}
+
+pub struct Iter<'a, K, V> {
+    iter: linked_list::Iter<'a, (K, V)>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, value)| (key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Iter<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(key, value)| (key, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LruCache;
+    use std::rc::Rc;
+    use std::cell::RefCell;
+
+    #[test]
+    fn test_empty() {
+        let mut lru_cache = LruCache::<u32, u32>::new(3);
+        assert!(lru_cache.is_empty());
+        assert_eq!(lru_cache.capacity(), 3);
+        assert_eq!(lru_cache.get(&1337), None);
+        assert_eq!(lru_cache.pop_lru(), None);
+    }
+
+    #[test]
+    fn test_huge_capacity() {
+        // Nothing is allocated up front for the capacity
+        let mut lru_cache = LruCache::new(usize::MAX);
+        lru_cache.put(1337, 42);
+
+        assert_eq!(lru_cache.capacity(), usize::MAX);
+        assert_eq!(lru_cache.get(&1337), Some(&42));
+    }
+
+    #[test]
+    fn test_put_get() {
+        let mut lru_cache = LruCache::new(3);
+        assert_eq!(lru_cache.put("foo", 1337), None);
+        assert_eq!(lru_cache.put("bar", 42), None);
+        assert_eq!(lru_cache.put("baz", 666), None);
+
+        // Promotes foo, so bar is evicted next
+        assert_eq!(lru_cache.get("foo"), Some(&1337));
+        assert_eq!(lru_cache.put("qux", 7), None);
+
+        assert_eq!(lru_cache.len(), 3);
+        assert!(!lru_cache.contains("bar"));
+        assert_eq!(lru_cache.iter().collect::<Vec<_>>(), vec![(&"qux", &7), (&"foo", &1337), (&"baz", &666)]);
+
+        // Replaces the value and promotes it
+        assert_eq!(lru_cache.put("baz", 999), Some(666));
+        *lru_cache.get_mut("foo").unwrap() += 1;
+        assert_eq!(lru_cache.iter().collect::<Vec<_>>(), vec![(&"foo", &1338), (&"baz", &999), (&"qux", &7)]);
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut lru_cache = LruCache::new(2);
+        lru_cache.put(1337, "foo");
+        lru_cache.put(42, "bar");
+
+        assert_eq!(lru_cache.peek(&1337), Some(&"foo"));
+        assert_eq!(lru_cache.peek_lru(), Some((&1337, &"foo")));
+
+        lru_cache.put(666, "baz");
+        assert_eq!(lru_cache.peek(&1337), None);
+    }
+
+    #[test]
+    fn test_pop() {
+        let mut lru_cache = LruCache::new(3);
+        lru_cache.put(1337, "foo");
+        lru_cache.put(42, "bar");
+        lru_cache.put(666, "baz");
+
+        assert_eq!(lru_cache.pop(&42), Some("bar"));
+        assert_eq!(lru_cache.pop(&42), None);
+        assert_eq!(lru_cache.pop_lru(), Some((1337, "foo")));
+        assert_eq!(lru_cache.pop_lru(), Some((666, "baz")));
+        assert!(lru_cache.is_empty());
+    }
+
+    #[test]
+    fn test_eviction_callback() {
+        let evicted = Rc::new(RefCell::new(Vec::new()));
+        let evicted_clone = evicted.clone();
+
+        let mut lru_cache = LruCache::with_eviction_callback(3, move |key, value| {
+            evicted_clone.borrow_mut().push((key, value));
+        });
+
+        (0 .. 5).for_each(|i| { lru_cache.put(i, i * 10); });
+        assert_eq!(*evicted.borrow(), vec![(0, 0), (1, 10)]);
+
+        // Neither popping nor replacing counts as an eviction
+        lru_cache.pop_lru();
+        lru_cache.put(3, 1337);
+        assert_eq!(evicted.borrow().len(), 2);
+
+        lru_cache.resize(1);
+        assert_eq!(*evicted.borrow(), vec![(0, 0), (1, 10), (4, 40)]);
+        assert_eq!(lru_cache.iter().collect::<Vec<_>>(), vec![(&3, &1337)]);
+
+        // Nothing fits in an empty cache
+        lru_cache.resize(0);
+        lru_cache.put(42, 666);
+        assert!(lru_cache.is_empty());
+        assert_eq!(*evicted.borrow(), vec![(0, 0), (1, 10), (4, 40), (3, 1337), (42, 666)]);
+    }
+
+    #[test]
+    fn test_resize_grow() {
+        let mut lru_cache = LruCache::new(1);
+        lru_cache.put(1337, 42);
+        lru_cache.resize(2);
+        lru_cache.put(666, 7);
+
+        assert_eq!(lru_cache.len(), 2);
+        assert_eq!(lru_cache.iter().rev().collect::<Vec<_>>(), vec![(&1337, &42), (&666, &7)]);
+    }
+}
@@ -0,0 +1,274 @@
+//! A lock-free queue with many producers and a single consumer.
+//!
+//! This is Dmitry Vyukov's intrusive MPSC queue. Producers link in new nodes with a
+//! single atomic swap, and only the consumer ever frees nodes, which it does once it
+//! has moved past them. As no producer reads a node after linking it in, this needs
+//! no epochs or hazard pointers to reclaim the memory safely.
+//!
+//! The queue is model checked with `loom`, by running
+//! `RUSTFLAGS="--cfg loom" cargo test --release --lib mpsc_queue`.
+
+use std::marker::PhantomData;
+use std::ptr;
+
+#[cfg(loom)]
+use loom::{cell::UnsafeCell, sync::{Arc, atomic::{AtomicPtr, Ordering}}};
+#[cfg(not(loom))]
+use std::sync::{Arc, atomic::{AtomicPtr, Ordering}};
+
+/// The part of loom's `UnsafeCell` we need, so loom can track access to the elements
+#[cfg(not(loom))]
+struct UnsafeCell<T>(std::cell::UnsafeCell<T>);
+
+#[cfg(not(loom))]
+impl<T> UnsafeCell<T> {
+    fn new(data: T) -> Self {
+        Self(std::cell::UnsafeCell::new(data))
+    }
+
+    fn with_mut<R>(&self, f: impl FnOnce(*mut T) -> R) -> R {
+        f(self.0.get())
+    }
+}
+
+struct Node<T> {
+    next: AtomicPtr<Node<T>>,
+    // None for the stub node the consumer sits on
+    data: UnsafeCell<Option<T>>,
+}
+
+impl<T> Node<T> {
+    fn new_ptr(data: Option<T>) -> *mut Node<T> {
+        Box::into_raw(Box::new(Node { next: AtomicPtr::new(ptr::null_mut()), data: UnsafeCell::new(data) }))
+    }
+}
+
+struct Queue<T> {
+    // The node pushed last, producers swap themselves in here
+    head: AtomicPtr<Node<T>>,
+    // The stub node in front of the next one to pop, only the consumer touches it
+    tail: AtomicPtr<Node<T>>,
+    _phantom: PhantomData<T>,
+}
+
+// Elements only move from the producers to the consumer, so they need not be Sync
+unsafe impl<T: Send> Send for Queue<T> {}
+unsafe impl<T: Send> Sync for Queue<T> {}
+
+impl<T> Drop for Queue<T> {
+    fn drop(&mut self) {
+        // Both ends are gone, so free the rest of the chain from the stub node on
+        let mut current = self.tail.load(Ordering::Relaxed);
+        while !current.is_null() {
+            let node = unsafe { Box::from_raw(current) };
+            current = node.next.load(Ordering::Relaxed);
+        }
+    }
+}
+
+/// Makes a new queue, returning its producer and consumer ends
+pub fn queue<T>() -> (Producer<T>, Consumer<T>) {
+    let stub = Node::new_ptr(None);
+    let queue = Arc::new(Queue {
+        head: AtomicPtr::new(stub),
+        tail: AtomicPtr::new(stub),
+        _phantom: PhantomData,
+    });
+
+    (Producer { queue: queue.clone() }, Consumer { queue })
+}
+
+/// The pushing end of the queue, which can be cloned for more producers
+pub struct Producer<T> {
+    queue: Arc<Queue<T>>,
+}
+
+impl<T> Producer<T> {
+    /// Pushes `data` to the back of the queue, without locking or waiting
+    pub fn push(&self, data: T) {
+        let node = Node::new_ptr(Some(data));
+
+        // Take the place as the last node, then link the node before it up to us
+        let previous = self.queue.head.swap(node, Ordering::AcqRel);
+        unsafe { (*previous).next.store(node, Ordering::Release) };
+    }
+}
+
+impl<T> Clone for Producer<T> {
+    fn clone(&self) -> Self {
+        Self { queue: self.queue.clone() }
+    }
+}
+
+/// The popping end of the queue, of which there is only ever one
+pub struct Consumer<T> {
+    queue: Arc<Queue<T>>,
+}
+
+impl<T> Consumer<T> {
+    /// Pops the front of the queue.
+    ///
+    /// Returns None if the queue is empty, or if the push of the front element is
+    /// still midway; then the element shows up on a later pop.
+    pub fn pop(&mut self) -> Option<T> {
+        let tail = self.queue.tail.load(Ordering::Relaxed);
+        let next = unsafe { (*tail).next.load(Ordering::Acquire) };
+
+        if next.is_null() {
+            return None;
+        }
+
+        // The next node becomes the stub, once its data is taken out
+        self.queue.tail.store(next, Ordering::Relaxed);
+        unsafe {
+            drop(Box::from_raw(tail));
+            (*next).data.with_mut(|data| (*data).take())
+        }
+    }
+
+    /// Checks if the queue is empty, counting pushes that are still midway
+    pub fn is_empty(&self) -> bool {
+        self.queue.tail.load(Ordering::Relaxed) == self.queue.head.load(Ordering::Acquire)
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_push_pop() {
+        let (producer, mut consumer) = queue();
+        assert!(consumer.is_empty());
+        assert_eq!(consumer.pop(), None);
+
+        producer.push(1337);
+        producer.push(42);
+        producer.clone().push(666);
+
+        assert!(!consumer.is_empty());
+        assert_eq!(consumer.pop(), Some(1337));
+        assert_eq!(consumer.pop(), Some(42));
+        assert_eq!(consumer.pop(), Some(666));
+        assert_eq!(consumer.pop(), None);
+        assert!(consumer.is_empty());
+    }
+
+    #[test]
+    fn test_drop_with_elements() {
+        let (producer, consumer) = queue();
+        producer.push(String::from("foo"));
+        producer.push(String::from("bar"));
+
+        drop(consumer);
+        producer.push(String::from("baz"));
+    }
+
+    #[test]
+    fn test_threads() {
+        let (producer, mut consumer) = queue();
+
+        let producers = (0 .. 4).map(|i| {
+            let producer = producer.clone();
+            thread::spawn(move || (0 .. 10_000).for_each(|j| producer.push((i, j))))
+        }).collect::<Vec<_>>();
+
+        let mut received = vec![Vec::new(); 4];
+        let mut count = 0;
+        while count < 40_000 {
+            match consumer.pop() {
+                Some((i, j)) => {
+                    received[i].push(j);
+                    count += 1;
+                }
+                None => thread::yield_now(),
+            }
+        }
+
+        producers.into_iter().for_each(|producer| producer.join().unwrap());
+
+        // Every producer's elements come out in the order they went in
+        received.iter().for_each(|received| assert_eq!(*received, (0 .. 10_000).collect::<Vec<_>>()));
+        assert_eq!(consumer.pop(), None);
+    }
+}
+
+#[cfg(all(test, loom))]
+mod loom_tests {
+    use super::*;
+    use loom::thread;
+
+    #[test]
+    fn test_concurrent_push() {
+        loom::model(|| {
+            let (producer, mut consumer) = queue();
+
+            let producers = (0 .. 2).map(|i| {
+                let producer = producer.clone();
+                thread::spawn(move || {
+                    producer.push((i, 0));
+                    producer.push((i, 1));
+                })
+            }).collect::<Vec<_>>();
+
+            producers.into_iter().for_each(|producer| producer.join().unwrap());
+
+            let mut received = Vec::new();
+            while let Some(data) = consumer.pop() {
+                received.push(data);
+            }
+
+            assert_eq!(received.len(), 4);
+            for i in 0 .. 2 {
+                let order = received.iter().filter(|(j, _)| *j == i).map(|(_, k)| *k).collect::<Vec<_>>();
+                assert_eq!(order, vec![0, 1]);
+            }
+        });
+    }
+
+    #[test]
+    fn test_pop_while_pushing() {
+        loom::model(|| {
+            let (producer, mut consumer) = queue();
+
+            let producers = (0 .. 2).map(|i| {
+                let producer = producer.clone();
+                thread::spawn(move || producer.push(i))
+            }).collect::<Vec<_>>();
+
+            // Whatever the consumer sees early has to be whole
+            let mut received = Vec::new();
+            if let Some(data) = consumer.pop() {
+                received.push(data);
+            }
+
+            producers.into_iter().for_each(|producer| producer.join().unwrap());
+
+            while let Some(data) = consumer.pop() {
+                received.push(data);
+            }
+
+            received.sort();
+            assert_eq!(received, vec![0, 1]);
+            assert!(consumer.is_empty());
+        });
+    }
+
+    #[test]
+    fn test_drop_while_pushing() {
+        loom::model(|| {
+            let (producer, mut consumer) = queue();
+
+            let pusher = thread::spawn(move || {
+                producer.push(String::from("foo"));
+                producer.push(String::from("bar"));
+            });
+
+            let _ = consumer.pop();
+            drop(consumer);
+
+            pusher.join().unwrap();
+        });
+    }
+}
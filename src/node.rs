@@ -1,9 +1,15 @@
 use std::ptr::NonNull;
+use std::sync::Arc;
 use std::fmt;
 
 pub type NodeLinkSome<T> = NonNull<Node<T>>;
 pub type NodeLink<T> = Option<NodeLinkSome<T>>;
 
+pub type ForwardNodeLinkSome<T> = NonNull<ForwardNode<T>>;
+pub type ForwardNodeLink<T> = Option<ForwardNodeLinkSome<T>>;
+
+pub type PersistentNodeLink<T> = Option<Arc<PersistentNode<T>>>;
+
 #[derive(Debug)]
 pub struct Node<T> {
     pub data: T,
@@ -23,6 +29,38 @@ impl<T: fmt::Display> fmt::Display for Node<T> {
     }
 }
 
+/// Node without a previous link, for singly linked lists
+#[derive(Debug)]
+pub struct ForwardNode<T> {
+    pub data: T,
+    pub next: ForwardNodeLink<T>,
+}
+
+impl<T> ForwardNode<T> {
+    pub fn new(data: T) -> Self {
+        Self { data, next: None }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for ForwardNode<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.data)
+    }
+}
+
+/// Immutable node, shared between all the lists that have it as a tail
+#[derive(Debug)]
+pub struct PersistentNode<T> {
+    pub data: T,
+    pub next: PersistentNodeLink<T>,
+}
+
+impl<T> PersistentNode<T> {
+    pub fn new(data: T, next: PersistentNodeLink<T>) -> Self {
+        Self { data, next }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -38,4 +76,12 @@ mod tests {
 
         assert_eq!(format!("{node}"), "1337");
     }
+
+    #[test]
+    fn test_forward_node_fmt() {
+        let node = ForwardNode::new(1337);
+
+        assert_eq!(format!("{node}"), "1337");
+        assert!(node.next.is_none());
+    }
 }
@@ -0,0 +1,236 @@
+use std::sync::Arc;
+use std::iter::FromIterator;
+use std::fmt;
+
+use crate::linked_list::LinkedList;
+use crate::node::{PersistentNode, PersistentNodeLink};
+
+/// An immutable cons list, where every list shares its tail with the lists it was made from.
+///
+/// `cons`, `head`, `tail` and `clone` are all O(1).
+pub struct PersistentList<T> {
+    head: PersistentNodeLink<T>,
+    length: usize,
+}
+
+impl<T> PersistentList<T> {
+    pub fn new() -> Self {
+        Self { head: None, length: 0 }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Makes a new list with `data` in front of this one
+    pub fn cons(&self, data: T) -> Self {
+        Self {
+            head: Some(Arc::new(PersistentNode::new(data, self.head.clone()))),
+            length: self.length + 1,
+        }
+    }
+
+    pub fn head(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.data)
+    }
+
+    /// Gets the list after the head, which is empty for the empty list
+    pub fn tail(&self) -> Self {
+        match &self.head {
+            Some(node) => Self { head: node.next.clone(), length: self.length - 1 },
+            None => Self::new(),
+        }
+    }
+
+    pub fn iter(&'_ self) -> Iter<'_, T> {
+        Iter {
+            current: self.head.as_deref(),
+            length: self.length,
+        }
+    }
+}
+
+impl<T> Clone for PersistentList<T> {
+    fn clone(&self) -> Self {
+        Self { head: self.head.clone(), length: self.length }
+    }
+}
+
+impl<T> Default for PersistentList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialEq> PartialEq for PersistentList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for PersistentList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> FromIterator<T> for PersistentList<T> {
+    fn from_iter<U: IntoIterator<Item = T>>(iter: U) -> Self {
+        iter.into_iter().collect::<LinkedList<T>>().into()
+    }
+}
+
+impl<T> From<LinkedList<T>> for PersistentList<T> {
+    fn from(mut list: LinkedList<T>) -> Self {
+        // Cons from the back, so the order is kept
+        let mut persistent_list = PersistentList::new();
+        while let Some(data) = list.pop_back() {
+            persistent_list = persistent_list.cons(data);
+        }
+
+        persistent_list
+    }
+}
+
+impl<T: Clone> From<PersistentList<T>> for LinkedList<T> {
+    fn from(mut list: PersistentList<T>) -> Self {
+        let mut linked_list = LinkedList::new();
+        let mut current = list.head.take();
+
+        // Move the elements out for as long as no other list shares the nodes
+        while let Some(node) = current {
+            match Arc::try_unwrap(node) {
+                Ok(node) => {
+                    linked_list.push_back(node.data);
+                    current = node.next;
+                }
+                Err(node) => {
+                    // The rest is shared, so it has to be cloned
+                    let shared = PersistentList { head: Some(node), length: list.length - linked_list.len() };
+                    shared.iter().for_each(|x| linked_list.push_back(x.clone()));
+                    break;
+                }
+            }
+        }
+
+        linked_list
+    }
+}
+
+impl<T> Drop for PersistentList<T> {
+    fn drop(&mut self) {
+        // Unwind the chain in a loop rather than recursively through Arc's drop,
+        // stopping at the first node some other list still holds on to
+        let mut current = self.head.take();
+        while let Some(node) = current {
+            match Arc::try_unwrap(node) {
+                Ok(mut node) => current = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+pub struct Iter<'a, T> {
+    current: Option<&'a PersistentNode<T>>,
+    length: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.current.map(|node| {
+            self.current = node.next.as_deref();
+            self.length -= 1;
+
+            &node.data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.length, Some(self.length))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {
+    fn len(&self) -> usize {
+        self.length
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty() {
+        let persistent_list = PersistentList::<u32>::new();
+        assert!(persistent_list.is_empty());
+        assert_eq!(persistent_list.len(), 0);
+        assert_eq!(persistent_list.head(), None);
+        assert!(persistent_list.tail().is_empty());
+    }
+
+    #[test]
+    fn test_cons_head_tail() {
+        let persistent_list = PersistentList::new().cons(666).cons(42);
+        let other_persistent_list = persistent_list.cons(1337);
+
+        // The old list is untouched
+        assert_eq!(persistent_list.len(), 2);
+        assert_eq!(persistent_list.head(), Some(&42));
+        assert_eq!(other_persistent_list.len(), 3);
+        assert_eq!(other_persistent_list.head(), Some(&1337));
+        assert_eq!(other_persistent_list.iter().collect::<Vec<_>>(), vec![&1337, &42, &666]);
+
+        // And the tail is shared
+        assert_eq!(other_persistent_list.tail(), persistent_list);
+        assert!(std::ptr::eq(other_persistent_list.tail().head().unwrap(), persistent_list.head().unwrap()));
+        assert_eq!(other_persistent_list.tail().tail().tail(), PersistentList::new());
+    }
+
+    #[test]
+    fn test_clone() {
+        let persistent_list = PersistentList::from_iter(vec![1337, 42, 666]);
+        let cloned_persistent_list = persistent_list.clone();
+        drop(persistent_list);
+
+        assert_eq!(cloned_persistent_list.len(), 3);
+        assert_eq!(cloned_persistent_list.iter().collect::<Vec<_>>(), vec![&1337, &42, &666]);
+    }
+
+    #[test]
+    fn test_drop_long() {
+        let mut persistent_list = PersistentList::new();
+        for i in 0 .. 1_000_000 {
+            persistent_list = persistent_list.cons(i);
+        }
+
+        let tail = persistent_list.tail();
+        drop(persistent_list);
+        assert_eq!(tail.len(), 999_999);
+        drop(tail);
+    }
+
+    #[test]
+    fn test_linked_list_conversion() {
+        let persistent_list = PersistentList::from(LinkedList::from(vec![1337, 42, 666]));
+        assert_eq!(persistent_list.len(), 3);
+        assert_eq!(persistent_list.iter().collect::<Vec<_>>(), vec![&1337, &42, &666]);
+
+        // Part of it is shared with another list, so that part is cloned
+        let tail = persistent_list.tail();
+        let linked_list = LinkedList::from(persistent_list);
+        assert_eq!(linked_list.len(), 3);
+        assert_eq!(linked_list.iter().collect::<Vec<_>>(), vec![&1337, &42, &666]);
+        assert_eq!(tail.iter().collect::<Vec<_>>(), vec![&42, &666]);
+
+        let linked_list = LinkedList::from(tail);
+        assert_eq!(linked_list.iter().rev().collect::<Vec<_>>(), vec![&666, &42]);
+    }
+}
@@ -0,0 +1,300 @@
+use std::marker::PhantomData;
+
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{FromParallelIterator, IndexedParallelIterator, IntoParallelIterator, ParallelExtend, ParallelIterator};
+
+use crate::linked_list::{self, LinkedList};
+use crate::list_slice::split_nodes;
+use crate::node::NodeLink;
+
+/// A parallel iterator over the elements of a `LinkedList`
+pub struct ParIter<'a, T> {
+    list: &'a LinkedList<T>,
+}
+
+impl<'a, T: Sync> ParallelIterator for ParIter<'a, T> {
+    type Item = &'a T;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.list.len())
+    }
+}
+
+impl<'a, T: Sync> IndexedParallelIterator for ParIter<'a, T> {
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(IterProducer {
+            front: self.list.head_link(),
+            back: self.list.foot_link(),
+            length: self.list.len(),
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'a, T: Sync> IntoParallelIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type Iter = ParIter<'a, T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIter { list: self }
+    }
+}
+
+struct IterProducer<'a, T> {
+    front: NodeLink<T>,
+    back: NodeLink<T>,
+    length: usize,
+    _phantom: PhantomData<&'a T>,
+}
+
+// It only hands out shared references, like a &LinkedList<T> would
+unsafe impl<'a, T: Sync> Send for IterProducer<'a, T> {}
+
+impl<'a, T: Sync> Producer for IterProducer<'a, T> {
+    type Item = &'a T;
+    type IntoIter = linked_list::Range<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        unsafe { linked_list::Range::from_nodes(self.front, self.back, self.length) }
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left_back, right_front) = split_nodes(self.front, self.back, self.length, index);
+
+        (
+            IterProducer { front: self.front, back: left_back, length: index, _phantom: PhantomData },
+            IterProducer { front: right_front, back: self.back, length: self.length - index, _phantom: PhantomData },
+        )
+    }
+}
+
+/// A parallel iterator over mutable references to the elements of a `LinkedList`
+pub struct ParIterMut<'a, T> {
+    list: &'a mut LinkedList<T>,
+}
+
+impl<'a, T: Send> ParallelIterator for ParIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.list.len())
+    }
+}
+
+impl<'a, T: Send> IndexedParallelIterator for ParIterMut<'a, T> {
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(IterMutProducer {
+            front: self.list.head_link(),
+            back: self.list.foot_link(),
+            length: self.list.len(),
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'a, T: Send> IntoParallelIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type Iter = ParIterMut<'a, T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIterMut { list: self }
+    }
+}
+
+struct IterMutProducer<'a, T> {
+    front: NodeLink<T>,
+    back: NodeLink<T>,
+    length: usize,
+    _phantom: PhantomData<&'a mut T>,
+}
+
+// Every producer has its own nodes, so this is like sending a &mut T for each
+unsafe impl<'a, T: Send> Send for IterMutProducer<'a, T> {}
+
+impl<'a, T: Send> Producer for IterMutProducer<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = linked_list::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        unsafe { linked_list::IterMut::from_nodes(self.front, self.back, self.length) }
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left_back, right_front) = split_nodes(self.front, self.back, self.length, index);
+
+        (
+            IterMutProducer { front: self.front, back: left_back, length: index, _phantom: PhantomData },
+            IterMutProducer { front: right_front, back: self.back, length: self.length - index, _phantom: PhantomData },
+        )
+    }
+}
+
+/// A parallel iterator moving the elements out of a `LinkedList`
+pub struct IntoParIter<T> {
+    list: LinkedList<T>,
+}
+
+impl<T: Send> ParallelIterator for IntoParIter<T> {
+    type Item = T;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.list.len())
+    }
+}
+
+impl<T: Send> IndexedParallelIterator for IntoParIter<T> {
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(IntoIterProducer { list: self.list })
+    }
+}
+
+impl<T: Send> IntoParallelIterator for LinkedList<T> {
+    type Item = T;
+    type Iter = IntoParIter<T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        IntoParIter { list: self }
+    }
+}
+
+struct IntoIterProducer<T> {
+    list: LinkedList<T>,
+}
+
+impl<T: Send> Producer for IntoIterProducer<T> {
+    type Item = T;
+    type IntoIter = linked_list::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.into_iter()
+    }
+
+    fn split_at(mut self, index: usize) -> (Self, Self) {
+        let right = self.list.split_off(index);
+
+        (self, IntoIterProducer { list: right })
+    }
+}
+
+impl<T: Send> FromParallelIterator<T> for LinkedList<T> {
+    fn from_par_iter<I: IntoParallelIterator<Item = T>>(par_iter: I) -> Self {
+        // Every thread builds its own lists, which are then spliced together in O(1)
+        par_iter.into_par_iter()
+            .fold(LinkedList::new, |mut list, x| {
+                list.push_back(x);
+                list
+            })
+            .reduce(LinkedList::new, |mut list, mut other| {
+                list.append(&mut other);
+                list
+            })
+    }
+}
+
+impl<T: Send> ParallelExtend<T> for LinkedList<T> {
+    fn par_extend<I: IntoParallelIterator<Item = T>>(&mut self, par_iter: I) {
+        let mut other = par_iter.into_par_iter().collect::<LinkedList<T>>();
+        self.append(&mut other);
+    }
+}
+
+impl<'a, T: Copy + Send + Sync + 'a> ParallelExtend<&'a T> for LinkedList<T> {
+    fn par_extend<I: IntoParallelIterator<Item = &'a T>>(&mut self, par_iter: I) {
+        self.par_extend(par_iter.into_par_iter().copied());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rayon::prelude::*;
+
+    use crate::linked_list::LinkedList;
+
+    #[test]
+    fn test_par_iter() {
+        let linked_list = (0 .. 10_000).collect::<LinkedList<u64>>();
+
+        assert_eq!(linked_list.par_iter().sum::<u64>(), (0 .. 10_000).sum::<u64>());
+        assert_eq!(linked_list.par_iter().len(), 10_000);
+
+        // Splitting all the way down still hands out every element in order
+        let collected = linked_list.par_iter().with_max_len(1).enumerate().collect::<Vec<_>>();
+        assert!(collected.iter().all(|(i, x)| *i as u64 == **x));
+        assert_eq!(collected.len(), 10_000);
+
+        let reversed = linked_list.par_iter().rev().take(3).copied().collect::<Vec<_>>();
+        assert_eq!(reversed, vec![9_999, 9_998, 9_997]);
+
+        assert_eq!(LinkedList::<u64>::new().par_iter().count(), 0);
+    }
+
+    #[test]
+    fn test_par_iter_mut() {
+        let mut linked_list = (0 .. 10_000).collect::<LinkedList<u64>>();
+        linked_list.par_iter_mut().with_max_len(7).for_each(|x| *x *= 2);
+
+        assert!(linked_list.iter().enumerate().all(|(i, x)| *x == 2 * i as u64));
+    }
+
+    #[test]
+    fn test_into_par_iter() {
+        let linked_list = (0 .. 10_000).map(|i| i.to_string()).collect::<LinkedList<_>>();
+
+        let collected = linked_list.into_par_iter().with_max_len(3).collect::<Vec<_>>();
+        assert_eq!(collected, (0 .. 10_000).map(|i| i.to_string()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_from_par_iter() {
+        let linked_list = (0 .. 100_000).into_par_iter().map(|i| i * 3).collect::<LinkedList<_>>();
+
+        assert_eq!(linked_list.len(), 100_000);
+        assert!(linked_list.iter().enumerate().all(|(i, x)| *x == i * 3));
+    }
+
+    #[test]
+    fn test_par_extend() {
+        let mut linked_list = LinkedList::from(vec![1337, 42]);
+        linked_list.par_extend(vec![666, 7]);
+        linked_list.par_extend(&[1, 2]);
+
+        assert_eq!(linked_list.len(), 6);
+        assert_eq!(linked_list.iter().collect::<Vec<_>>(), vec![&1337, &42, &666, &7, &1, &2]);
+    }
+}
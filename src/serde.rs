@@ -0,0 +1,161 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{Deserialize, DeserializeSeed, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+use crate::linked_list::LinkedList;
+
+impl<T: Serialize> Serialize for LinkedList<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Give the length up front, some binary formats require it
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for element in self.iter() {
+            seq.serialize_element(element)?;
+        }
+
+        seq.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for LinkedList<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(LinkedListVisitor { _phantom: PhantomData })
+    }
+
+    fn deserialize_in_place<D: Deserializer<'de>>(deserializer: D, place: &mut Self) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(InPlaceVisitor { list: place })
+    }
+}
+
+struct LinkedListVisitor<T> {
+    _phantom: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for LinkedListVisitor<T> {
+    type Value = LinkedList<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        // Stream the elements directly onto the list
+        let mut list = LinkedList::new();
+        while let Some(element) = seq.next_element()? {
+            list.push_back(element);
+        }
+
+        Ok(list)
+    }
+}
+
+struct InPlaceVisitor<'a, T> {
+    list: &'a mut LinkedList<T>,
+}
+
+impl<'a, 'de, T: Deserialize<'de>> Visitor<'de> for InPlaceVisitor<'a, T> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut length = 0;
+
+        // Deserialize into the nodes we already have
+        for element in self.list.iter_mut() {
+            if seq.next_element_seed(InPlaceSeed(element))?.is_none() {
+                break;
+            }
+            length += 1;
+        }
+
+        // The sequence was shorter than the list, so drop the leftover nodes
+        while self.list.len() > length {
+            self.list.pop_back();
+        }
+
+        // The sequence was longer than the list, so allocate the rest
+        while let Some(element) = seq.next_element()? {
+            self.list.push_back(element);
+        }
+
+        Ok(())
+    }
+}
+
+/// Seed deserializing in place into an existing element
+struct InPlaceSeed<'a, T>(&'a mut T);
+
+impl<'a, 'de, T: Deserialize<'de>> DeserializeSeed<'de> for InPlaceSeed<'a, T> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        T::deserialize_in_place(deserializer, self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_round_trip() {
+        let linked_list = LinkedList::from(vec![1337, 42, 666]);
+
+        let json = serde_json::to_string(&linked_list).unwrap();
+        assert_eq!(json, "[1337,42,666]");
+
+        let other_linked_list: LinkedList<u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(other_linked_list.len(), 3);
+        assert_eq!(linked_list, other_linked_list);
+    }
+
+    #[test]
+    fn test_json_empty() {
+        let linked_list = LinkedList::<u32>::new();
+
+        let json = serde_json::to_string(&linked_list).unwrap();
+        assert_eq!(json, "[]");
+
+        let other_linked_list: LinkedList<u32> = serde_json::from_str(&json).unwrap();
+        assert!(other_linked_list.is_empty());
+    }
+
+    #[test]
+    fn test_bincode_round_trip() {
+        let linked_list = LinkedList::from(vec![
+            String::from("foo"),
+            String::from("bar"),
+            String::from("baz"),
+        ]);
+
+        let bytes = bincode::serialize(&linked_list).unwrap();
+        let other_linked_list: LinkedList<String> = bincode::deserialize(&bytes).unwrap();
+
+        assert_eq!(other_linked_list.len(), 3);
+        assert_eq!(linked_list, other_linked_list);
+    }
+
+    #[test]
+    fn test_deserialize_in_place() {
+        let mut linked_list = LinkedList::<u32>::from(vec![1, 2, 3]);
+        let first_node = linked_list.get(0).unwrap() as *const u32;
+
+        // Shorter sequence truncates the list, but keeps the nodes
+        let mut deserializer = serde_json::Deserializer::from_str("[1337, 42]");
+        LinkedList::deserialize_in_place(&mut deserializer, &mut linked_list).unwrap();
+        assert_eq!(linked_list, LinkedList::from(vec![1337, 42]));
+        assert_eq!(linked_list.len(), 2);
+        assert_eq!(linked_list.get(0).unwrap() as *const u32, first_node);
+
+        // Longer sequence extends the list
+        let mut deserializer = serde_json::Deserializer::from_str("[1, 2, 3, 4]");
+        LinkedList::deserialize_in_place(&mut deserializer, &mut linked_list).unwrap();
+        assert_eq!(linked_list, LinkedList::from(vec![1, 2, 3, 4]));
+        assert_eq!(linked_list.len(), 4);
+        assert_eq!(linked_list.get(0).unwrap() as *const u32, first_node);
+    }
+}